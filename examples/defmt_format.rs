@@ -0,0 +1,54 @@
+//! Logging configuration structures with `defmt`
+//!
+//! Requires the `defmt` feature. On a real target, link a `defmt` transport
+//! (e.g. `defmt-rtt`) instead of the no-op logger below.
+
+use renik::{
+    AuthRequirements, BluetoothConnectionPhase, BluetoothConnectionState, BluetoothDeviceInfo,
+    ConnHandle, Error, PeripheralPreferredParams, WifiConfig,
+};
+
+#[defmt::global_logger]
+struct NoopLogger;
+
+unsafe impl defmt::Logger for NoopLogger {
+    fn acquire() {}
+    unsafe fn flush() {}
+    unsafe fn release() {}
+    unsafe fn write(_bytes: &[u8]) {}
+}
+
+fn main() -> Result<(), Error> {
+    let wifi = WifiConfig::new(b"MyNetwork", b"password123")?;
+    let mut device = BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], b"Speaker")?;
+    device.set_pairing_key(b"1234")?;
+
+    // Passwords, pairing keys and link keys are never written to the log
+    defmt::info!("wifi: {}", wifi);
+    defmt::info!("device: {}", device);
+    defmt::info!(
+        "phase: {}, handle: {}",
+        BluetoothConnectionPhase::Connected,
+        ConnHandle::new(0x0042)
+    );
+    defmt::info!(
+        "severity: {}, summary: {}",
+        BluetoothConnectionPhase::Failed.severity(),
+        BluetoothConnectionState::default().summary()
+    );
+
+    let params = device.get_connection_params();
+    defmt::info!(
+        "role: {}, rssi: {}, codecs: {}",
+        params.get_role(),
+        params.rssi_typed(),
+        device.codec_support()
+    );
+    defmt::info!(
+        "auth: {}, ppcp: {}",
+        AuthRequirements::BONDING | AuthRequirements::MITM,
+        PeripheralPreferredParams::default()
+    );
+    defmt::info!("error: {}", Error::ConfigLocked);
+    Ok(())
+}
@@ -23,11 +23,12 @@
 //!
 //! ## Finite State Machine
 //!
-//! The Bluetooth connection FSM supports 13 distinct phases:
+//! The Bluetooth connection FSM supports 14 distinct phases:
 //!
 //! ```text
 //! Idle → Discovery → Connecting → Connected → Authenticating → SettingUpEncryption
-//!   ↑        ↓           ↓           ↓             ↓                    ↓
+//!   ↑        ↓           ↓           ↓             ↓  ↘              ↗  ↓
+//!   │        ↓           ↓           ↓             ↓    Bonding ─────┘  ↓
 //!   └─────────────────────────────────────────────────────────────→ Failed
 //!                                   ↓             ↓                    ↓
 //!                            ServiceDiscovery → FullyConnected → Ready → Maintaining
@@ -82,7 +83,7 @@
 //! # Ok::<(), renik::Error>(())
 //! ```
 
-use crate::Error;
+use crate::{Error, TransitionError, limits, util};
 use bytemuck::{Pod, Zeroable};
 
 /// Magic number used to validate Bluetooth device configuration structures
@@ -125,16 +126,22 @@ const BLUETOOTH_CONNECTION_STATE_MAGIC: u32 = 0x4254_4353;
 /// assert_eq!(device_list.len(), 2);
 /// ```
 #[derive(Debug, Clone, Copy, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct BluetoothDeviceList {
     /// Magic number for structure validation (0x42544C53)
     magic: u32, // 4-byte aligned
     /// Array of Bluetooth device configurations
-    devices: [BluetoothDeviceInfo; 10], // 4-byte aligned
+    devices: [BluetoothDeviceInfo; limits::MAX_DEVICES], // 4-byte aligned
+    /// Most recently removed device, kept for `undo_remove`
+    last_removed: BluetoothDeviceInfo, // 4-byte aligned
     /// Number of devices currently in the list
     device_count: u8, // 1-byte aligned
+    /// Whether `last_removed` holds a device (0 = empty, 1 = valid)
+    last_removed_valid: u8, // 1-byte aligned
     /// Padding to ensure proper alignment
-    _padding: [u8; 3], // Ensures 4-byte alignment
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _padding: [u8; 2], // Ensures 4-byte alignment
 }
 
 impl Default for BluetoothDeviceList {
@@ -146,13 +153,52 @@ impl Default for BluetoothDeviceList {
         Self {
             magic: BLUETOOTH_DEVICE_LIST_MAGIC,
             devices: Default::default(),
+            last_removed: BluetoothDeviceInfo::default(),
             device_count: 0,
-            _padding: [0; 3],
+            last_removed_valid: 0,
+            _padding: [0; 2],
         }
     }
 }
 
 impl BluetoothDeviceList {
+    /// Creates an all-zero device list with only the list's magic number set
+    ///
+    /// The unused device slots and the `undo_remove` slot are left fully
+    /// zeroed, including their own magic numbers.
+    #[must_use]
+    pub fn new_zeroed_with_magic() -> Self {
+        Self {
+            magic: BLUETOOTH_DEVICE_LIST_MAGIC,
+            ..Zeroable::zeroed()
+        }
+    }
+
+    /// Builds a device list from an iterator of device configurations
+    ///
+    /// Devices are added in iteration order, as if by repeated `add_device`.
+    ///
+    /// # Parameters
+    /// - `iter`: Device configurations to add
+    ///
+    /// # Returns
+    /// - `Ok(BluetoothDeviceList)` holding every yielded device
+    /// - `Err(Error)` if the iterator yields more devices than the list can hold
+    ///
+    /// # Errors
+    /// Returns `Error::DeviceListFull` if the iterator yields more than
+    /// `limits::MAX_DEVICES` devices. The iterator is not consumed past the
+    /// first device that does not fit.
+    pub fn try_from_iter<I: IntoIterator<Item = BluetoothDeviceInfo>>(
+        iter: I,
+    ) -> Result<Self, Error> {
+        let mut list = Self::default();
+        for device in iter {
+            list.add_device(device)?;
+        }
+        Ok(list)
+    }
+
     /// Adds a Bluetooth device configuration to the list
     ///
     /// # Parameters
@@ -164,19 +210,27 @@ impl BluetoothDeviceList {
     ///
     /// # Errors
     /// Returns `Error::DeviceListFull` if the device list is already at maximum capacity.
+    #[allow(clippy::cast_possible_truncation)]
     pub fn add_device(&mut self, device_config: BluetoothDeviceInfo) -> Result<(), Error> {
-        if self.device_count as usize >= self.devices.len() {
+        let count = self.len();
+        if count >= self.devices.len() {
             return Err(Error::DeviceListFull);
         }
 
-        self.devices[self.device_count as usize] = device_config;
-        self.device_count += 1;
+        self.devices[count] = device_config;
+        // Safe cast: count is below the 10 device slots
+        self.device_count = count as u8 + 1;
 
         Ok(())
     }
 
     /// Removes a Bluetooth device configuration from the list
     ///
+    /// The removed device is kept as the last removed device so the removal
+    /// can be reverted with `undo_remove`. It replaces any previously kept
+    /// device. The slot vacated at the end of the list is reset to defaults
+    /// so no stale pairing data remains in it.
+    ///
     /// # Parameters
     /// - `index`: Index of the device to remove (0-based)
     ///
@@ -186,21 +240,134 @@ impl BluetoothDeviceList {
     ///
     /// # Errors
     /// Returns `Error::IndexOutOfBounds` if the specified index is not valid.
+    #[allow(clippy::cast_possible_truncation)]
     pub fn remove_device(&mut self, index: usize) -> Result<(), Error> {
-        if index >= self.device_count as usize {
+        if index >= self.len() {
             return Err(Error::IndexOutOfBounds);
         }
 
+        self.last_removed = self.devices[index];
+        self.last_removed_valid = 1;
+
         // Shift devices down to fill the gap
-        for i in index..(self.device_count as usize - 1) {
+        let last = self.len() - 1;
+        for i in index..last {
             self.devices[i] = self.devices[i + 1];
         }
 
-        self.device_count -= 1;
+        // Safe cast: last is below the 10 device slots
+        self.device_count = last as u8;
+        self.devices[last] = BluetoothDeviceInfo::default();
+
+        Ok(())
+    }
+
+    /// Removes the device with the given MAC address from the list
+    ///
+    /// Behaves like `remove_device` for the first device whose MAC address
+    /// matches.
+    ///
+    /// # Parameters
+    /// - `mac`: MAC address of the device to remove
+    ///
+    /// # Returns
+    /// - `Ok(BluetoothDeviceInfo)` with the removed device
+    /// - `Err(Error)` if no device has the given MAC address
+    ///
+    /// # Errors
+    /// Returns `Error::NotFound` if no stored device matches `mac`.
+    pub fn remove_by_mac(&mut self, mac: &[u8; 6]) -> Result<BluetoothDeviceInfo, Error> {
+        let index = self.position_by_mac(*mac).ok_or(Error::NotFound)?;
+
+        let removed = self.devices[index];
+        self.remove_device(index)?;
+        Ok(removed)
+    }
+
+    /// Removes the device with the given MAC address without returning it
+    ///
+    /// Behaves like `remove_by_mac`, but no copy of the removed device, and
+    /// therefore of its pairing key and link key, is handed to the caller.
+    /// Intended for unpair requests that only carry the MAC address.
+    ///
+    /// # Parameters
+    /// - `mac`: MAC address of the device to remove
+    ///
+    /// # Returns
+    /// - `Ok(())` if the device was removed
+    /// - `Err(Error)` if no device has the given MAC address
+    ///
+    /// # Errors
+    /// Returns `Error::NotFound` if no stored device matches `mac`.
+    pub fn remove_device_by_mac(&mut self, mac: &[u8; 6]) -> Result<(), Error> {
+        let index = self.position_by_mac(*mac).ok_or(Error::NotFound)?;
+        self.remove_device(index)
+    }
+
+    /// Removes every device for which `keep` returns `false`
+    ///
+    /// The remaining devices keep their relative order. All slots vacated at
+    /// the end of the list are reset to defaults, so no pairing key or link
+    /// key of a removed device remains in the buffer. Removed devices are not
+    /// kept for `undo_remove`.
+    ///
+    /// # Parameters
+    /// - `keep`: Predicate deciding which devices stay in the list
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn compact<F: Fn(&BluetoothDeviceInfo) -> bool>(&mut self, keep: F) {
+        let count = self.len();
+        let mut kept = 0;
+
+        for i in 0..count {
+            if keep(&self.devices[i]) {
+                self.devices[kept] = self.devices[i];
+                kept += 1;
+            }
+        }
+
+        for slot in &mut self.devices[kept..count] {
+            *slot = BluetoothDeviceInfo::default();
+        }
+
+        // Safe cast: kept never exceeds the previous device count
+        self.device_count = kept as u8;
+    }
+
+    /// Re-adds the most recently removed device to the end of the list
+    ///
+    /// # Returns
+    /// - `Ok(())` if the device was restored successfully
+    /// - `Err(Error)` if there is nothing to restore or the list is full
+    ///
+    /// # Errors
+    /// Returns `Error::NotFound` if no removed device is kept, or
+    /// `Error::DeviceListFull` if the list is already at maximum capacity.
+    /// On error the kept device is left in place.
+    pub fn undo_remove(&mut self) -> Result<(), Error> {
+        if self.last_removed_valid == 0 {
+            return Err(Error::NotFound);
+        }
 
+        self.add_device(self.last_removed)?;
+        self.clear_last_removed();
         Ok(())
     }
 
+    /// Returns the most recently removed device, if one is kept
+    #[must_use]
+    pub fn last_removed(&self) -> Option<&BluetoothDeviceInfo> {
+        (self.last_removed_valid != 0).then_some(&self.last_removed)
+    }
+
+    /// Discards the most recently removed device
+    ///
+    /// The kept record, including its pairing key and link key, is reset to
+    /// defaults so that it can no longer be restored.
+    pub fn clear_last_removed(&mut self) {
+        self.last_removed = BluetoothDeviceInfo::default();
+        self.last_removed_valid = 0;
+    }
+
     /// Returns a reference to a Bluetooth device configuration
     ///
     /// # Parameters
@@ -213,20 +380,54 @@ impl BluetoothDeviceList {
     /// # Errors
     /// Returns `Error::IndexOutOfBounds` if the specified index is not valid.
     pub fn get_device(&self, index: usize) -> Result<&BluetoothDeviceInfo, Error> {
-        if index >= self.device_count as usize {
+        if index >= self.len() {
             return Err(Error::IndexOutOfBounds);
         }
 
         Ok(&self.devices[index])
     }
 
+    /// Looks up a stored device by its MAC address
+    ///
+    /// # Parameters
+    /// - `mac`: MAC address of the device to look up
+    ///
+    /// # Returns
+    /// - `Some(&BluetoothDeviceInfo)` for the first device with a matching MAC address
+    /// - `None` if no stored device matches
+    #[must_use]
+    pub fn find_device_by_mac(&self, mac: &[u8; 6]) -> Option<&BluetoothDeviceInfo> {
+        self.position_by_mac(*mac).map(|index| &self.devices[index])
+    }
+
+    /// Looks up a stored device by its MAC address for modification
+    ///
+    /// # Parameters
+    /// - `mac`: MAC address of the device to look up
+    ///
+    /// # Returns
+    /// - `Some(&mut BluetoothDeviceInfo)` for the first device with a matching MAC address
+    /// - `None` if no stored device matches
+    pub fn find_device_by_mac_mut(&mut self, mac: &[u8; 6]) -> Option<&mut BluetoothDeviceInfo> {
+        self.position_by_mac(*mac)
+            .map(|index| &mut self.devices[index])
+    }
+
+    /// Returns the index of the first stored device with the given MAC address
+    fn position_by_mac(&self, mac: [u8; 6]) -> Option<usize> {
+        self.devices[..self.len()]
+            .iter()
+            .position(|device| device.mac_address == mac)
+    }
+
     /// Returns the number of devices in the list
     ///
     /// # Returns
-    /// The current device count
+    /// The current device count, clamped to the list capacity should the
+    /// stored count be corrupt
     #[must_use]
     pub fn len(&self) -> usize {
-        self.device_count as usize
+        usize::from(self.device_count).min(self.devices.len())
     }
 
     /// Checks if the device list is empty
@@ -238,6 +439,252 @@ impl BluetoothDeviceList {
     pub fn is_empty(&self) -> bool {
         self.device_count == 0
     }
+
+    /// Returns an iterator over the stored devices
+    ///
+    /// Only the first `len()` entries are yielded, in list order; unused
+    /// slots are skipped. `&BluetoothDeviceList` also implements
+    /// `IntoIterator`, so the list can be used directly in a `for` loop.
+    pub fn iter(&self) -> core::slice::Iter<'_, BluetoothDeviceInfo> {
+        self.devices[..self.len()].iter()
+    }
+
+    /// Returns an iterator that allows modifying the stored devices
+    ///
+    /// Only the first `len()` entries are yielded, in list order; unused
+    /// slots are skipped.
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, BluetoothDeviceInfo> {
+        let len = self.len();
+        self.devices[..len].iter_mut()
+    }
+
+    /// Returns an iterator over the stored devices together with their indices
+    ///
+    /// Only the first `len()` entries are yielded; unused slots are skipped.
+    /// The index can be passed to `get_device` or `remove_device`.
+    pub fn enumerate(&self) -> impl Iterator<Item = (usize, &BluetoothDeviceInfo)> {
+        self.devices[..self.len()].iter().enumerate()
+    }
+
+    /// Returns an iterator over the stored devices of one device type
+    ///
+    /// # Parameters
+    /// - `device_type`: One of the `BluetoothDeviceInfo::DEVICE_TYPE_*` constants
+    ///
+    /// # Returns
+    /// The devices whose `get_device_type()` equals `device_type`, in list order
+    pub fn iter_by_type(&self, device_type: u8) -> impl Iterator<Item = &BluetoothDeviceInfo> {
+        self.devices[..self.len()]
+            .iter()
+            .filter(move |device| device.get_device_type() == device_type)
+    }
+
+    /// Returns the sum of the connection counts of all stored devices
+    ///
+    /// # Returns
+    /// The total number of successful connections, widened to `u64` so the
+    /// sum cannot overflow
+    #[must_use]
+    pub fn total_connection_count(&self) -> u64 {
+        self.devices[..self.len()]
+            .iter()
+            .map(|device| u64::from(device.connection_count))
+            .sum()
+    }
+
+    /// Returns the device with the highest connection count
+    ///
+    /// # Returns
+    /// - `Some(&BluetoothDeviceInfo)` for the most connected device; on a tie
+    ///   the one with the lowest index is returned
+    /// - `None` if the list is empty
+    #[must_use]
+    pub fn most_connected_device(&self) -> Option<&BluetoothDeviceInfo> {
+        self.devices[..self.len()].iter().reduce(|best, device| {
+            if device.connection_count > best.connection_count {
+                device
+            } else {
+                best
+            }
+        })
+    }
+
+    /// Copies the MAC address of every stored device into a caller buffer
+    ///
+    /// # Parameters
+    /// - `out`: Destination buffer, must hold at least `len()` addresses
+    ///
+    /// # Returns
+    /// - `Ok(usize)` with the number of addresses written
+    /// - `Err(Error)` if the buffer is too small
+    ///
+    /// # Errors
+    /// Returns `Error::BufferTooSmall` if `out` is shorter than the number of stored devices.
+    pub fn collect_macs(&self, out: &mut [[u8; 6]]) -> Result<usize, Error> {
+        let count = self.len();
+        if out.len() < count {
+            return Err(Error::BufferTooSmall);
+        }
+
+        for (slot, device) in out.iter_mut().zip(&self.devices[..count]) {
+            *slot = device.mac_address;
+        }
+
+        Ok(count)
+    }
+
+    /// Compares the list against a previous snapshot by MAC address
+    ///
+    /// # Parameters
+    /// - `previous`: Earlier snapshot of the list
+    /// - `out_added`: Receives the MAC addresses present now but not in `previous`
+    /// - `out_removed`: Receives the MAC addresses present in `previous` but not now
+    ///
+    /// # Returns
+    /// - `Ok((added, removed))` with the number of addresses written to each buffer
+    /// - `Err(Error)` if either buffer is too small
+    ///
+    /// # Errors
+    /// Returns `Error::BufferTooSmall` if `out_added` or `out_removed` cannot
+    /// hold every changed address. The buffer contents are unspecified then.
+    ///
+    /// # Note
+    /// Devices whose MAC address is in both lists are not reported, even if
+    /// other fields of their records changed.
+    pub fn diff(
+        &self,
+        previous: &BluetoothDeviceList,
+        out_added: &mut [[u8; 6]],
+        out_removed: &mut [[u8; 6]],
+    ) -> Result<(usize, usize), Error> {
+        let added = self.macs_missing_from(previous, out_added)?;
+        let removed = previous.macs_missing_from(self, out_removed)?;
+        Ok((added, removed))
+    }
+
+    /// Writes the MAC addresses stored here but not in `other` into `out`
+    fn macs_missing_from(
+        &self,
+        other: &BluetoothDeviceList,
+        out: &mut [[u8; 6]],
+    ) -> Result<usize, Error> {
+        let others = &other.devices[..other.len()];
+        let mut count = 0;
+
+        for device in &self.devices[..self.len()] {
+            if others.iter().any(|o| o.mac_address == device.mac_address) {
+                continue;
+            }
+            *out.get_mut(count).ok_or(Error::BufferTooSmall)? = device.mac_address;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    /// Sorts the stored devices by RSSI, strongest signal first
+    ///
+    /// Uses the ordering of `Rssi`, so devices with an unknown RSSI (-127)
+    /// end up last. The sort is stable: devices with equal RSSI keep their
+    /// relative order. It runs in place without allocating.
+    pub fn sort_by_rssi(&mut self) {
+        let len = self.len();
+        let devices = &mut self.devices[..len];
+        let rssi = |device: &BluetoothDeviceInfo| device.connection_params.rssi_typed();
+
+        // Insertion sort: stable, allocation-free and cheap for 10 entries
+        for i in 1..devices.len() {
+            let mut j = i;
+            while j > 0 && rssi(&devices[j - 1]) < rssi(&devices[j]) {
+                devices.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+    }
+
+    /// Counts the slots holding a valid device, regardless of `len()`
+    ///
+    /// All slots are scanned, so this is independent of a possibly corrupt
+    /// device count.
+    ///
+    /// # Returns
+    /// The number of slots for which `BluetoothDeviceInfo::is_valid` holds
+    #[must_use]
+    pub fn count_valid_entries(&self) -> usize {
+        self.devices
+            .iter()
+            .filter(|device| device.is_valid())
+            .count()
+    }
+
+    /// Recomputes the device count from the stored slots
+    ///
+    /// The count is set to the number of leading valid slots, i.e. it ends at
+    /// the first slot that fails `BluetoothDeviceInfo::is_valid`. Slots past
+    /// the new count are reset to defaults, even if they hold valid devices,
+    /// so the list is consistent again afterwards.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn repair_count(&mut self) {
+        let count = self
+            .devices
+            .iter()
+            .take_while(|device| device.is_valid())
+            .count();
+
+        for slot in &mut self.devices[count..] {
+            *slot = BluetoothDeviceInfo::default();
+        }
+
+        // Safe cast: count never exceeds the 10 device slots
+        self.device_count = count as u8;
+    }
+
+    /// Checks whether any MAC address is stored more than once
+    ///
+    /// # Returns
+    /// - `true` if at least two stored devices share a MAC address
+    /// - `false` otherwise
+    #[must_use]
+    pub fn has_duplicates(&self) -> bool {
+        self.find_duplicate().is_some()
+    }
+
+    /// Finds the first MAC address that is stored more than once
+    ///
+    /// The list is scanned in order and the address of the earliest device
+    /// that has a later duplicate is returned. The list is not modified.
+    ///
+    /// # Returns
+    /// - `Some([u8; 6])` with the duplicated MAC address
+    /// - `None` if all stored MAC addresses are unique
+    #[must_use]
+    pub fn find_duplicate(&self) -> Option<[u8; 6]> {
+        let devices = &self.devices[..self.len()];
+        devices.iter().enumerate().find_map(|(i, device)| {
+            devices[i + 1..]
+                .iter()
+                .any(|other| other.mac_address == device.mac_address)
+                .then_some(device.mac_address)
+        })
+    }
+}
+
+impl<'a> IntoIterator for &'a BluetoothDeviceList {
+    type Item = &'a BluetoothDeviceInfo;
+    type IntoIter = core::slice::Iter<'a, BluetoothDeviceInfo>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut BluetoothDeviceList {
+    type Item = &'a mut BluetoothDeviceInfo;
+    type IntoIter = core::slice::IterMut<'a, BluetoothDeviceInfo>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
 }
 
 /// Bluetooth connection state structure
@@ -267,6 +714,7 @@ impl BluetoothDeviceList {
 /// assert!(connection_state.is_connected());
 /// ```
 #[derive(Debug, Clone, Copy, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct BluetoothConnectionState {
     /// Magic number for structure validation (0x42544353)
@@ -280,6 +728,7 @@ pub struct BluetoothConnectionState {
     /// Current connection phase
     connection_phase: u8, // 1-byte aligned (maps to BluetoothConnectionPhase)
     /// Padding to ensure proper alignment
+    #[cfg_attr(feature = "serde", serde(skip))]
     _padding: [u8; 1], // Ensures 4-byte alignment
 }
 
@@ -301,6 +750,18 @@ impl Default for BluetoothConnectionState {
 }
 
 impl BluetoothConnectionState {
+    /// Creates an all-zero connection state with only the magic number set
+    ///
+    /// The remote device configuration is left fully zeroed, including its
+    /// own magic number; set it with `set_remote_device`.
+    #[must_use]
+    pub fn new_zeroed_with_magic() -> Self {
+        Self {
+            magic: BLUETOOTH_CONNECTION_STATE_MAGIC,
+            ..Zeroable::zeroed()
+        }
+    }
+
     /// Sets the remote Bluetooth device configuration
     ///
     /// # Parameters
@@ -338,6 +799,18 @@ impl BluetoothConnectionState {
         &self.device_config
     }
 
+    /// Consumes the connection state and returns the remote device record
+    ///
+    /// Useful for moving the record into a `BluetoothDeviceList` once the
+    /// connection is no longer tracked.
+    ///
+    /// # Returns
+    /// The Bluetooth device configuration
+    #[must_use]
+    pub fn into_remote_device(self) -> BluetoothDeviceInfo {
+        self.device_config
+    }
+
     /// Returns the connection status
     ///
     /// # Returns
@@ -379,6 +852,54 @@ impl BluetoothConnectionState {
         (self.connection_flags & 0x02) != 0
     }
 
+    /// Clears every connection flag
+    ///
+    /// Resets the connected and authenticated bits, along with any other
+    /// bit of `connection_flags`, while keeping the remote device, phase and
+    /// link quality. Intended for resetting transient status on disconnect.
+    pub fn clear_connection_flags(&mut self) {
+        self.connection_flags = 0;
+    }
+
+    /// Returns the raw connection flags
+    ///
+    /// # Returns
+    /// The connection flags byte (bit 0 = connected, bit 1 = authenticated)
+    #[must_use]
+    pub fn connection_flags_raw(&self) -> u8 {
+        self.connection_flags
+    }
+
+    /// Returns the remote device's link key
+    ///
+    /// # Returns
+    /// - `Some(&[u8; 16])` if the remote device's security info holds a valid link key
+    /// - `None` otherwise
+    #[must_use]
+    pub fn link_key(&self) -> Option<&[u8; 16]> {
+        let security = &self.device_config.security_info;
+        (security.link_key_valid != 0).then_some(&security.link_key)
+    }
+
+    /// Returns whether the remote device's security info reports an encrypted link
+    ///
+    /// # Returns
+    /// - `true` if the link is encrypted
+    /// - `false` otherwise
+    #[must_use]
+    pub fn is_link_encrypted(&self) -> bool {
+        self.device_config.security_info.encrypted != 0
+    }
+
+    /// Returns the remote device's security level
+    ///
+    /// # Returns
+    /// The security level (0x01-0x04)
+    #[must_use]
+    pub fn security_level(&self) -> u8 {
+        self.device_config.security_info.security_level
+    }
+
     /// Sets the remote device address
     ///
     /// # Parameters
@@ -407,13 +928,16 @@ impl BluetoothConnectionState {
     /// Gets the connection handle
     ///
     /// # Returns
-    /// Optional connection handle
+    /// - `Some(ConnHandle)` if a valid handle is stored
+    /// - `None` if no handle is set or the stored value lies in the reserved
+    ///   range 0x0F00-0xFFFF
     #[must_use]
     pub fn get_connection_handle(&self) -> Option<ConnHandle> {
-        if self.device_config.connection_params.connection_handle.raw() == 0 {
+        let handle = self.device_config.connection_params.connection_handle;
+        if handle.raw() == 0 || !ConnHandle::is_valid_raw(handle.raw()) {
             None
         } else {
-            Some(self.device_config.connection_params.connection_handle)
+            Some(handle)
         }
     }
 
@@ -445,24 +969,127 @@ impl BluetoothConnectionState {
     /// Gets the connection phase
     ///
     /// # Returns
-    /// The current connection phase
+    /// The current connection phase, or `Idle` if the stored byte is not a
+    /// valid phase
     #[must_use]
     pub fn get_connection_phase(&self) -> BluetoothConnectionPhase {
-        // Convert u8 back to enum, default to Idle if invalid
-        match self.connection_phase {
-            1 => BluetoothConnectionPhase::Discovery,
-            2 => BluetoothConnectionPhase::Connecting,
-            3 => BluetoothConnectionPhase::Connected,
-            4 => BluetoothConnectionPhase::Authenticating,
-            5 => BluetoothConnectionPhase::SettingUpEncryption,
-            6 => BluetoothConnectionPhase::FullyConnected,
-            7 => BluetoothConnectionPhase::ServiceDiscovery,
-            8 => BluetoothConnectionPhase::Ready,
-            9 => BluetoothConnectionPhase::Maintaining,
-            10 => BluetoothConnectionPhase::Reconnecting,
-            11 => BluetoothConnectionPhase::Failed,
-            12 => BluetoothConnectionPhase::Disconnecting,
-            _ => BluetoothConnectionPhase::Idle, // Default for 0 and invalid values
+        BluetoothConnectionPhase::try_from(self.connection_phase).unwrap_or_default()
+    }
+
+    /// Gets the connection phase, rejecting corrupt values
+    ///
+    /// Unlike `get_connection_phase`, an unknown stored byte is reported
+    /// instead of being mapped to `Idle`.
+    ///
+    /// # Returns
+    /// - `Ok(BluetoothConnectionPhase)` with the current connection phase
+    /// - `Err(Error)` if the stored byte is not a valid phase
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidConnectionPhase` if the stored byte is above 13.
+    pub fn try_get_connection_phase(&self) -> Result<BluetoothConnectionPhase, Error> {
+        BluetoothConnectionPhase::try_from(self.connection_phase)
+    }
+
+    /// Returns the time left before the link supervision timeout would expire
+    ///
+    /// The idle time is measured from the remote device's `last_activity`
+    /// timestamp (seconds) to `now`, and subtracted from the supervision
+    /// timeout (10ms units).
+    ///
+    /// # Parameters
+    /// - `now`: Current time in seconds since epoch, same clock as `last_activity`
+    ///
+    /// # Returns
+    /// - `Some(u32)` with the remaining milliseconds, saturating at 0
+    /// - `None` if no supervision timeout is set
+    #[must_use]
+    pub fn supervision_remaining_ms(&self, now: u32) -> Option<u32> {
+        let params = &self.device_config.connection_params;
+        if params.supervision_timeout == 0 {
+            return None;
+        }
+
+        let timeout_ms = u32::from(params.supervision_timeout) * 10;
+        let idle_ms = now
+            .saturating_sub(params.last_activity)
+            .saturating_mul(1000);
+        Some(timeout_ms.saturating_sub(idle_ms))
+    }
+
+    /// Checks that the status flags, phase and security info agree
+    ///
+    /// # Returns
+    /// - `Ok(())` if no contradiction was found
+    /// - `Err(Error)` if the state is inconsistent
+    ///
+    /// # Errors
+    /// Returns `Error::CorruptData` if any of the following holds:
+    /// - the phase is secure but the remote device's link is not encrypted
+    /// - the connected flag is set without a connection handle
+    /// - the stored connection handle lies in the reserved range
+    /// - the authenticated flag is set outside `Authenticating`, `Bonding`,
+    ///   `SettingUpEncryption` and the phases where `is_secure` holds
+    pub fn check_consistency(&self) -> Result<(), Error> {
+        use BluetoothConnectionPhase::{Authenticating, Bonding, SettingUpEncryption};
+
+        let phase = self.get_connection_phase();
+
+        if phase.is_secure() && !self.is_link_encrypted() {
+            return Err(Error::CorruptData);
+        }
+        if self.is_connected() && self.get_connection_handle().is_none() {
+            return Err(Error::CorruptData);
+        }
+        if !ConnHandle::is_valid_raw(self.device_config.connection_params.connection_handle.raw()) {
+            return Err(Error::CorruptData);
+        }
+        let may_be_authenticated =
+            phase.is_secure() || matches!(phase, Authenticating | Bonding | SettingUpEncryption);
+        if self.is_authenticated() && !may_be_authenticated {
+            return Err(Error::CorruptData);
+        }
+
+        Ok(())
+    }
+
+    /// Updates the status flags to match the current connection phase
+    ///
+    /// The mapping is:
+    /// - connected: set for phases where `is_connected` holds
+    /// - authenticated: set from `Bonding` onwards, i.e. for `Bonding`,
+    ///   `SettingUpEncryption` and every phase where `is_secure` holds
+    /// - encrypted (in the remote device's security info): set for phases
+    ///   where `is_secure` holds
+    ///
+    /// Every flag is cleared in the remaining phases, e.g. `Idle`, `Failed` or
+    /// `Reconnecting`. Other bits of the connection flags are left unchanged.
+    pub fn sync_flags_from_phase(&mut self) {
+        use BluetoothConnectionPhase::{Bonding, SettingUpEncryption};
+
+        let phase = self.get_connection_phase();
+        let secure = phase.is_secure();
+
+        self.set_connected(phase.is_connected());
+        self.set_authenticated(secure || matches!(phase, Bonding | SettingUpEncryption));
+        self.device_config.security_info.encrypted = u8::from(secure);
+    }
+
+    /// Returns a flat snapshot of the connection status
+    ///
+    /// # Returns
+    /// A `ConnectionSummary` with the phase, status flags, link quality,
+    /// RSSI and connection handle
+    #[must_use]
+    pub fn summary(&self) -> ConnectionSummary {
+        ConnectionSummary {
+            phase: self.get_connection_phase(),
+            connected: self.is_connected(),
+            authenticated: self.is_authenticated(),
+            encrypted: self.is_link_encrypted(),
+            link_quality: self.link_quality,
+            rssi: self.device_config.connection_params.rssi,
+            handle: self.get_connection_handle(),
         }
     }
 
@@ -475,27 +1102,55 @@ impl BluetoothConnectionState {
     /// - `true` if the transition is valid
     /// - `false` if the transition is not allowed
     pub fn advance_to_phase(&mut self, next_phase: BluetoothConnectionPhase) -> bool {
-        let current = self.get_connection_phase();
+        self.advance_to_phase_checked(next_phase).is_ok()
+    }
 
-        // Simple rule-based validation instead of exhaustive matching
-        let valid_transition = next_phase == BluetoothConnectionPhase::Idle
-            || Self::is_valid_transition(current, next_phase);
+    /// Advances to the next connection phase, reporting why a transition was rejected
+    ///
+    /// # Parameters
+    /// - `next_phase`: The next phase to transition to
+    ///
+    /// # Returns
+    /// - `Ok(())` if the transition is valid and was applied
+    /// - `Err(TransitionError)` if the transition is not allowed
+    ///
+    /// # Errors
+    /// Returns `TransitionError::IllegalTransition` with the current and requested
+    /// phases if the FSM does not allow the transition. The phase is left unchanged.
+    pub fn advance_to_phase_checked(
+        &mut self,
+        next_phase: BluetoothConnectionPhase,
+    ) -> Result<(), TransitionError> {
+        let current = self.get_connection_phase();
 
-        if valid_transition {
-            self.set_connection_phase(next_phase);
+        if !Self::is_allowed_transition(current, next_phase) {
+            return Err(TransitionError::IllegalTransition {
+                from: current,
+                to: next_phase,
+            });
         }
 
-        valid_transition
+        self.set_connection_phase(next_phase);
+        Ok(())
     }
 
-    /// Helper function to check if a state transition is valid
-    fn is_valid_transition(
+    /// Complete transition rule: any phase may reset to Idle, otherwise the
+    /// per-phase rules of `is_valid_transition` apply
+    fn is_allowed_transition(
         current: BluetoothConnectionPhase,
         next: BluetoothConnectionPhase,
     ) -> bool {
-        use BluetoothConnectionPhase::{
-            Authenticating, Connected, Connecting, Disconnecting, Discovery, Failed,
-            FullyConnected, Idle, Maintaining, Ready, Reconnecting, ServiceDiscovery,
+        next == BluetoothConnectionPhase::Idle || Self::is_valid_transition(current, next)
+    }
+
+    /// Helper function to check if a state transition is valid
+    fn is_valid_transition(
+        current: BluetoothConnectionPhase,
+        next: BluetoothConnectionPhase,
+    ) -> bool {
+        use BluetoothConnectionPhase::{
+            Authenticating, Bonding, Connected, Connecting, Disconnecting, Discovery, Failed,
+            FullyConnected, Idle, Maintaining, Ready, Reconnecting, ServiceDiscovery,
             SettingUpEncryption,
         };
 
@@ -504,7 +1159,11 @@ impl BluetoothConnectionState {
             Discovery => next == Connecting,
             Connecting => matches!(next, Connected | Failed),
             Connected => matches!(next, Authenticating | ServiceDiscovery | Disconnecting),
-            Authenticating => matches!(next, SettingUpEncryption | Failed | Disconnecting),
+            // Bonding is optional, e.g. LE Just Works goes straight to encryption
+            Authenticating => {
+                matches!(next, Bonding | SettingUpEncryption | Failed | Disconnecting)
+            }
+            Bonding => matches!(next, SettingUpEncryption | Failed | Disconnecting),
             SettingUpEncryption => matches!(next, FullyConnected | Failed | Disconnecting),
             FullyConnected => matches!(next, ServiceDiscovery | Ready | Disconnecting),
             ServiceDiscovery => matches!(next, Ready | Failed | Disconnecting),
@@ -517,8 +1176,83 @@ impl BluetoothConnectionState {
     }
 }
 
+/// Returns every legal connection phase transition as `(from, to)` pairs
+///
+/// The pairs are derived from the same rules `advance_to_phase` enforces,
+/// including the reset to `Idle` that is allowed from every phase (and from
+/// `Idle` itself). They are yielded grouped by `from`, in numeric phase order.
+///
+/// # Examples
+/// ```
+/// use renik::{BluetoothConnectionPhase, all_valid_transitions};
+/// use BluetoothConnectionPhase::{Connected, Connecting, Idle, Ready};
+///
+/// assert!(all_valid_transitions().any(|edge| edge == (Connecting, Connected)));
+/// assert!(!all_valid_transitions().any(|edge| edge == (Idle, Ready)));
+///
+/// // Every phase can reset to Idle
+/// let resets = all_valid_transitions().filter(|&(_, to)| to == Idle).count();
+/// assert_eq!(resets, BluetoothConnectionPhase::ALL.len());
+/// ```
+pub fn all_valid_transitions()
+-> impl Iterator<Item = (BluetoothConnectionPhase, BluetoothConnectionPhase)> {
+    BluetoothConnectionPhase::ALL.into_iter().flat_map(|from| {
+        BluetoothConnectionPhase::ALL
+            .into_iter()
+            .filter(move |&to| BluetoothConnectionState::is_allowed_transition(from, to))
+            .map(move |to| (from, to))
+    })
+}
+
+/// Checks whether a MAC address can identify a single peer
+///
+/// # Parameters
+/// - `mac`: MAC address to check
+///
+/// # Returns
+/// - `false` for the all-zero (unset) and all-0xFF (broadcast) addresses
+/// - `true` otherwise
+///
+/// # Examples
+/// ```
+/// use renik::is_valid_unicast_mac;
+///
+/// assert!(is_valid_unicast_mac(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]));
+/// assert!(!is_valid_unicast_mac(&[0x00; 6]));
+/// assert!(!is_valid_unicast_mac(&[0xFF; 6]));
+/// ```
+#[must_use]
+pub fn is_valid_unicast_mac(mac: &[u8; 6]) -> bool {
+    *mac != [0x00; 6] && *mac != [0xFF; 6]
+}
+
+/// Flat snapshot of a Bluetooth connection's status
+///
+/// Returned by [`BluetoothConnectionState::summary`]. It carries only the
+/// status fields callers typically report, without the full nested
+/// [`BluetoothDeviceInfo`], which makes it cheap to copy across an IPC boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ConnectionSummary {
+    /// Current connection phase
+    pub phase: BluetoothConnectionPhase,
+    /// Whether the connected flag is set
+    pub connected: bool,
+    /// Whether the authenticated flag is set
+    pub authenticated: bool,
+    /// Whether the remote device's security info reports an encrypted link
+    pub encrypted: bool,
+    /// Link quality (0-255)
+    pub link_quality: u8,
+    /// RSSI in dBm (-127 when unknown)
+    pub rssi: i8,
+    /// Connection handle, if one is assigned
+    pub handle: Option<ConnHandle>,
+}
+
 /// Connection parameters for Bluetooth devices
 #[derive(Debug, Clone, Copy, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct BluetoothConnectionParams {
     /// Connection handle assigned by the controller
@@ -541,8 +1275,13 @@ pub struct BluetoothConnectionParams {
     pub connected_at: u32,
     /// Last activity timestamp (seconds since epoch)
     pub last_activity: u32,
+    /// Local role in the connection (0 = unknown, 1 = central, 2 = peripheral)
+    pub role: u8,
     /// Padding for alignment
-    _padding: [u8; 4],
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _padding: u8,
+    /// Negotiated ATT MTU in bytes (range: 23-517)
+    att_mtu: u16,
 }
 
 impl Default for BluetoothConnectionParams {
@@ -558,20 +1297,251 @@ impl Default for BluetoothConnectionParams {
             rssi: -127,
             connected_at: 0,
             last_activity: 0,
-            _padding: [0; 4],
+            role: ConnectionRole::Unknown as u8,
+            _padding: 0,
+            att_mtu: Self::ATT_MTU_MIN,
+        }
+    }
+}
+
+impl BluetoothConnectionParams {
+    /// Default and minimum ATT MTU for LE connections
+    pub const ATT_MTU_MIN: u16 = 23;
+    /// Maximum ATT MTU (512-byte attribute value plus headers)
+    pub const ATT_MTU_MAX: u16 = 517;
+
+    /// Returns a builder that validates interval, latency and timeout
+    ///
+    /// # Examples
+    /// ```
+    /// use renik::BluetoothConnectionParams;
+    ///
+    /// // 30 ms interval, latency 4, 2 s supervision timeout
+    /// let params = BluetoothConnectionParams::builder()
+    ///     .interval(24)
+    ///     .latency(4)
+    ///     .timeout(200)
+    ///     .build()?;
+    /// assert_eq!(params.connection_interval, 24);
+    ///
+    /// // 300 ms of allowed silence is too short for (1 + 4) * 30 ms * 2
+    /// assert!(BluetoothConnectionParams::builder()
+    ///     .interval(24)
+    ///     .latency(4)
+    ///     .timeout(30)
+    ///     .build()
+    ///     .is_err());
+    /// # Ok::<(), renik::Error>(())
+    /// ```
+    #[must_use]
+    pub fn builder() -> BluetoothConnectionParamsBuilder {
+        BluetoothConnectionParamsBuilder::default()
+    }
+
+    /// Returns the RSSI as a typed value
+    ///
+    /// # Returns
+    /// The raw `rssi` field wrapped in `Rssi`, so the -127 "unknown" sentinel
+    /// cannot be mistaken for a real signal strength
+    #[must_use]
+    pub fn rssi_typed(&self) -> Rssi {
+        Rssi::from(self.rssi)
+    }
+
+    /// Sets the local role in the connection
+    ///
+    /// # Parameters
+    /// - `role`: Role this device had in the connection
+    pub fn set_role(&mut self, role: ConnectionRole) {
+        self.role = role as u8;
+    }
+
+    /// Gets the local role in the connection
+    ///
+    /// # Returns
+    /// The connection role, `ConnectionRole::Unknown` for unrecognized values
+    #[must_use]
+    pub fn get_role(&self) -> ConnectionRole {
+        match self.role {
+            1 => ConnectionRole::Central,
+            2 => ConnectionRole::Peripheral,
+            _ => ConnectionRole::Unknown,
+        }
+    }
+
+    /// Returns true if this device was the central (master) of the connection
+    #[must_use]
+    pub fn is_central(&self) -> bool {
+        self.get_role() == ConnectionRole::Central
+    }
+
+    /// Returns true if this device was the peripheral (slave) of the connection
+    #[must_use]
+    pub fn is_peripheral(&self) -> bool {
+        self.get_role() == ConnectionRole::Peripheral
+    }
+
+    /// Stores the ATT MTU negotiated by the MTU exchange
+    ///
+    /// # Parameters
+    /// - `mtu`: Negotiated MTU in bytes (23-517)
+    ///
+    /// # Returns
+    /// - `Ok(())` if the MTU was stored
+    /// - `Err(Error)` if the MTU is out of range
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidConnectionParams` if `mtu` is outside
+    /// `ATT_MTU_MIN..=ATT_MTU_MAX`.
+    pub fn set_att_mtu(&mut self, mtu: u16) -> Result<(), Error> {
+        if !(Self::ATT_MTU_MIN..=Self::ATT_MTU_MAX).contains(&mtu) {
+            return Err(Error::InvalidConnectionParams);
+        }
+
+        self.att_mtu = mtu;
+        Ok(())
+    }
+
+    /// Returns the negotiated ATT MTU
+    ///
+    /// # Returns
+    /// The MTU in bytes. Stored values below `ATT_MTU_MIN`, e.g. from zeroed
+    /// storage, read as `ATT_MTU_MIN`.
+    #[must_use]
+    pub fn att_mtu(&self) -> u16 {
+        self.att_mtu.max(Self::ATT_MTU_MIN)
+    }
+}
+
+/// Builder for [`BluetoothConnectionParams`] with specification checks
+///
+/// Created by [`BluetoothConnectionParams::builder`]. Fields that are not set
+/// default to zero, which `build` rejects for the interval and timeout.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BluetoothConnectionParamsBuilder {
+    interval: u16,
+    latency: u16,
+    timeout: u16,
+}
+
+impl BluetoothConnectionParamsBuilder {
+    /// Sets the connection interval in 1.25ms units (range: 6-3200)
+    #[must_use]
+    pub fn interval(mut self, interval: u16) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the connection latency in connection events (range: 0-499)
+    #[must_use]
+    pub fn latency(mut self, latency: u16) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Sets the supervision timeout in 10ms units (range: 10-3200)
+    #[must_use]
+    pub fn timeout(mut self, timeout: u16) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Validates the parameters and builds the connection parameters
+    ///
+    /// # Returns
+    /// - `Ok(BluetoothConnectionParams)` with the validated timing and all
+    ///   other fields at their defaults
+    /// - `Err(Error)` if the parameters would be rejected by a controller
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidConnectionParams` if any value is out of range,
+    /// or if the supervision timeout is not longer than
+    /// `(1 + latency) * interval * 2`.
+    pub fn build(self) -> Result<BluetoothConnectionParams, Error> {
+        let in_range = (6..=3200).contains(&self.interval)
+            && self.latency <= 499
+            && (10..=3200).contains(&self.timeout);
+
+        // timeout * 10ms > (1 + latency) * interval * 1.25ms * 2, scaled by 4/10
+        let timeout_ok =
+            u32::from(self.timeout) * 4 > (1 + u32::from(self.latency)) * u32::from(self.interval);
+
+        if !in_range || !timeout_ok {
+            return Err(Error::InvalidConnectionParams);
+        }
+
+        Ok(BluetoothConnectionParams {
+            connection_interval: self.interval,
+            connection_latency: self.latency,
+            supervision_timeout: self.timeout,
+            ..BluetoothConnectionParams::default()
+        })
+    }
+}
+
+/// Local role in a Bluetooth connection
+///
+/// Stored in `BluetoothConnectionParams` so a reconnection can be initiated
+/// from the same side as the original connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum ConnectionRole {
+    /// Role was not recorded
+    #[default]
+    Unknown = 0,
+    /// Central (master): this device initiated the connection
+    Central = 1,
+    /// Peripheral (slave): this device advertised and accepted the connection
+    Peripheral = 2,
+}
+
+/// Peripheral-preferred connection parameters (PPCP)
+///
+/// Mirrors the GAP Peripheral Preferred Connection Parameters characteristic.
+/// Units match `BluetoothConnectionParams`; any field may be
+/// `NO_PREFERENCE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(C)]
+pub struct PeripheralPreferredParams {
+    /// Minimum connection interval in 1.25ms units (range: 6-3200)
+    pub min_interval: u16,
+    /// Maximum connection interval in 1.25ms units (range: 6-3200)
+    pub max_interval: u16,
+    /// Peripheral latency (range: 0-499)
+    pub latency: u16,
+    /// Supervision timeout in 10ms units (range: 10-3200)
+    pub timeout: u16,
+}
+
+impl PeripheralPreferredParams {
+    /// Field value meaning the peripheral has no preference
+    pub const NO_PREFERENCE: u16 = 0xFFFF;
+}
+
+impl Default for PeripheralPreferredParams {
+    fn default() -> Self {
+        Self {
+            min_interval: Self::NO_PREFERENCE,
+            max_interval: Self::NO_PREFERENCE,
+            latency: Self::NO_PREFERENCE,
+            timeout: Self::NO_PREFERENCE,
         }
     }
 }
 
 /// Security information for Bluetooth connections
 #[derive(Debug, Clone, Copy, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct BluetoothSecurityInfo {
     /// Link key for authentication (16 bytes)
-    pub link_key: [u8; 16],
+    pub link_key: [u8; limits::LINK_KEY_LEN],
     /// Link key type (0x00-0x07)
     pub link_key_type: u8,
-    /// Authentication requirements
+    /// Authentication requirements (see `AuthRequirements`)
     pub auth_requirements: u8,
     /// IO capabilities (0x00-0x04)
     pub io_capabilities: u8,
@@ -590,17 +1560,50 @@ pub struct BluetoothSecurityInfo {
     /// Whether MITM protection is required
     pub mitm_required: u8,
     /// Padding for alignment
+    #[cfg_attr(feature = "serde", serde(skip))]
     _padding: [u8; 6],
 }
 
+impl BluetoothSecurityInfo {
+    /// Returns an owned copy of the link key
+    ///
+    /// Useful for handing the key to another task or over a channel without
+    /// borrowing the security information.
+    #[must_use]
+    pub fn link_key_copy(&self) -> [u8; 16] {
+        self.link_key
+    }
+
+    /// Sets the authentication requirements from typed flags
+    ///
+    /// # Parameters
+    /// - `requirements`: Validated authentication requirement flags
+    pub fn set_auth_requirements_typed(&mut self, requirements: AuthRequirements) {
+        self.auth_requirements = requirements.bits();
+    }
+
+    /// Returns the authentication requirements as typed flags
+    ///
+    /// # Returns
+    /// - `Ok(AuthRequirements)` if the stored byte is well-formed
+    /// - `Err(Error)` if it has reserved bits set
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidCredentialFormat` if any reserved bit of the raw
+    /// `auth_requirements` field is set.
+    pub fn auth_requirements_typed(&self) -> Result<AuthRequirements, Error> {
+        AuthRequirements::from_bits(self.auth_requirements)
+    }
+}
+
 impl Default for BluetoothSecurityInfo {
     fn default() -> Self {
         Self {
-            link_key: [0; 16],
+            link_key: [0; limits::LINK_KEY_LEN],
             link_key_type: 0,
             auth_requirements: 0,
             io_capabilities: 0,
-            security_level: 1,
+            security_level: limits::MIN_SECURITY_LEVEL,
             pin_length: 0,
             link_key_valid: 0,
             authenticated: 0,
@@ -614,18 +1617,20 @@ impl Default for BluetoothSecurityInfo {
 
 /// Complete Bluetooth device information for storage
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct BluetoothDeviceInfo {
     /// Magic number for validation
     magic: u32,
     /// Bluetooth MAC address (6 bytes)
-    mac_address: [u8; 6],
+    mac_address: [u8; limits::MAC_ADDRESS_LEN],
     /// Fixed-size buffer for device name (maximum 32 bytes)
-    device_name: [u8; 32],
+    device_name: [u8; limits::MAX_DEVICE_NAME_LEN],
     /// Actual length of the device name (0-32 bytes)
     device_name_len: u8,
     /// Fixed-size buffer for pairing key/PIN (maximum 64 bytes)
-    pairing_key: [u8; 64],
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    pairing_key: [u8; limits::MAX_PAIRING_KEY_LEN],
     /// Actual length of the pairing key (0-64 bytes)
     pairing_key_len: u8,
     /// Device class of device (24-bit value)
@@ -634,10 +1639,13 @@ pub struct BluetoothDeviceInfo {
     device_type: u8,
     /// Device flags (paired, trusted, etc.)
     flags: u8,
-    /// Padding for 4-byte alignment (1 byte to align next u32)
-    _padding1: u8,
+    /// Padding for 4-byte alignment (3 bytes to align next u32)
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _padding1: [u8; 3],
     /// Number of successful connections
     connection_count: u32,
+    /// Number of connection attempts, successful or not
+    attempt_count: u32,
     /// Last seen timestamp (seconds since epoch)
     last_seen: u32,
     /// Last successful connection timestamp
@@ -652,8 +1660,22 @@ pub struct BluetoothDeviceInfo {
     product_id: u16,
     /// Version (if available)
     version: u16,
-    /// Final padding for structure alignment
-    _padding2: u16,
+    /// Whether `ppcp` holds the peripheral's preferred parameters
+    ppcp_valid: u8,
+    /// Padding to align the preferred parameters
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _padding2: u8,
+    /// Peripheral-preferred connection parameters
+    ppcp: PeripheralPreferredParams,
+    /// User-assigned alias (maximum 32 bytes)
+    alias: [u8; limits::MAX_DEVICE_NAME_LEN],
+    /// Actual length of the alias (0 = no alias)
+    alias_len: u8,
+    /// Audio codecs supported by the peer (see `Codec`, 0 = unknown)
+    supported_codecs: u8,
+    /// Padding for 4-byte alignment
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _padding3: [u8; 2],
 }
 
 // Manual implementation for Pod/Zeroable to handle alignment properly
@@ -664,16 +1686,17 @@ impl Default for BluetoothDeviceInfo {
     fn default() -> Self {
         Self {
             magic: BLUETOOTH_CONFIG_MAGIC,
-            mac_address: [0; 6],
-            device_name: [0; 32],
+            mac_address: [0; limits::MAC_ADDRESS_LEN],
+            device_name: [0; limits::MAX_DEVICE_NAME_LEN],
             device_name_len: 0,
-            pairing_key: [0; 64],
+            pairing_key: [0; limits::MAX_PAIRING_KEY_LEN],
             pairing_key_len: 0,
             class_of_device: [0; 3],
             device_type: 0,
             flags: 0,
-            _padding1: 0,
+            _padding1: [0; 3],
             connection_count: 0,
+            attempt_count: 0,
             last_seen: 0,
             last_connected: 0,
             connection_params: BluetoothConnectionParams::default(),
@@ -681,11 +1704,38 @@ impl Default for BluetoothDeviceInfo {
             vendor_id: 0,
             product_id: 0,
             version: 0,
+            ppcp_valid: 0,
             _padding2: 0,
+            ppcp: PeripheralPreferredParams::default(),
+            alias: [0; limits::MAX_DEVICE_NAME_LEN],
+            alias_len: 0,
+            supported_codecs: 0,
+            _padding3: [0; 2],
         }
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for BluetoothDeviceInfo {
+    /// Formats the device without its pairing key or link key
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let mac = &self.mac_address;
+        defmt::write!(
+            f,
+            "BluetoothDeviceInfo {{ mac_address: {=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}, device_name: {=[u8]:a}, device_type: {=u8}, flags: {=u8:#04x}, .. }}",
+            mac[0],
+            mac[1],
+            mac[2],
+            mac[3],
+            mac[4],
+            mac[5],
+            self.get_device_name(),
+            self.device_type,
+            self.flags,
+        );
+    }
+}
+
 /// Device type constants based on Class of Device major class
 impl BluetoothDeviceInfo {
     pub const DEVICE_TYPE_UNKNOWN: u8 = 0;
@@ -733,7 +1783,7 @@ impl BluetoothDeviceInfo {
     /// # Errors
     /// Returns `Error::InvalidBluetoothDeviceInfo` if the device name exceeds 32 bytes.
     pub fn new(mac_address: &[u8; 6], device_name: &[u8]) -> Result<Self, Error> {
-        if device_name.len() > 32 {
+        if device_name.len() > limits::MAX_DEVICE_NAME_LEN {
             return Err(Error::InvalidBluetoothDeviceInfo);
         }
 
@@ -743,10 +1793,65 @@ impl BluetoothDeviceInfo {
         Ok(device)
     }
 
+    /// Creates an all-zero device configuration with only the magic number set
+    ///
+    /// Unlike `default()`, the nested connection parameters and security
+    /// information are left zeroed instead of getting their non-zero defaults
+    /// (-127 RSSI, security level 1). This gives a byte-exact starting point
+    /// for importing from formats that do not share those defaults.
+    #[must_use]
+    pub fn new_zeroed_with_magic() -> Self {
+        Self {
+            magic: BLUETOOTH_CONFIG_MAGIC,
+            ..Zeroable::zeroed()
+        }
+    }
+
     /// Validates the device info structure
+    ///
+    /// Requires the correct magic number and a MAC address accepted by
+    /// `has_valid_mac`.
     #[must_use]
     pub fn is_valid(&self) -> bool {
-        self.magic == BLUETOOTH_CONFIG_MAGIC && !self.mac_address.iter().all(|&b| b == 0)
+        self.magic == BLUETOOTH_CONFIG_MAGIC && self.has_valid_mac()
+    }
+
+    /// Checks whether the stored MAC address can identify a peer
+    ///
+    /// # Returns
+    /// - `false` for the all-zero (unset) and all-0xFF (broadcast) addresses
+    /// - `true` otherwise
+    #[must_use]
+    pub fn has_valid_mac(&self) -> bool {
+        is_valid_unicast_mac(&self.mac_address)
+    }
+
+    /// Computes a short, salted fingerprint of the device's MAC address
+    ///
+    /// The fingerprint is a 64-bit FNV-1a hash over the MAC address followed
+    /// by `salt`. It is stable for a given MAC and salt, so it can be used to
+    /// count unique peers without transmitting real addresses. Using a
+    /// different salt per installation prevents correlating devices across
+    /// installations.
+    ///
+    /// # Note
+    /// FNV-1a is not a cryptographic hash. The fingerprint resists casual
+    /// correlation but not a brute-force search over the MAC address space
+    /// by someone who knows the salt.
+    #[must_use]
+    pub fn fingerprint(&self, salt: &[u8]) -> [u8; 8] {
+        const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+        let hash = self
+            .mac_address
+            .iter()
+            .chain(salt)
+            .fold(FNV_OFFSET_BASIS, |hash, &b| {
+                (hash ^ u64::from(b)).wrapping_mul(FNV_PRIME)
+            });
+
+        hash.to_be_bytes()
     }
 
     /// Sets the MAC address
@@ -765,18 +1870,88 @@ impl BluetoothDeviceInfo {
     ///
     /// # Errors
     /// Returns `Error::InvalidBluetoothDeviceInfo` if the device name exceeds 32 bytes.
-    #[allow(clippy::cast_possible_truncation)]
     pub fn set_device_name(&mut self, device_name: &[u8]) -> Result<(), Error> {
-        if device_name.len() > 32 {
+        if device_name.len() > limits::MAX_DEVICE_NAME_LEN {
             return Err(Error::InvalidBluetoothDeviceInfo);
         }
 
-        self.device_name_len = device_name.len() as u8;
+        self.set_device_name_truncating(device_name);
+        Ok(())
+    }
+
+    /// Sets the device name, truncating it to 32 bytes if necessary
+    ///
+    /// # Parameters
+    /// - `device_name`: Device name as byte slice (any length)
+    ///
+    /// # Returns
+    /// - `true` if the name was longer than 32 bytes and got truncated
+    /// - `false` if the whole name was stored
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn set_device_name_truncating(&mut self, device_name: &[u8]) -> bool {
+        let len = device_name.len().min(self.device_name.len());
+
+        self.device_name_len = len as u8;
         self.device_name.fill(0);
-        self.device_name[..device_name.len()].copy_from_slice(device_name);
+        self.device_name[..len].copy_from_slice(&device_name[..len]);
+        debug_assert!(util::tail_is_zero(&self.device_name, len));
+        len < device_name.len()
+    }
+
+    /// Sets a user-assigned alias for the device
+    ///
+    /// The alias is shown instead of the advertised device name by
+    /// `display_name`. An empty alias removes it.
+    ///
+    /// # Parameters
+    /// - `alias`: Alias as byte slice (max 32 bytes)
+    ///
+    /// # Returns
+    /// - `Ok(())` if the alias was set successfully
+    /// - `Err(Error)` if the alias is too long
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidBluetoothDeviceInfo` if the alias exceeds 32 bytes.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn set_alias(&mut self, alias: &[u8]) -> Result<(), Error> {
+        if alias.len() > limits::MAX_DEVICE_NAME_LEN {
+            return Err(Error::InvalidBluetoothDeviceInfo);
+        }
+
+        // Safe cast: we've already validated the length is within 32 bytes
+        self.alias_len = alias.len() as u8;
+        self.alias.fill(0);
+        self.alias[..alias.len()].copy_from_slice(alias);
+        debug_assert!(util::tail_is_zero(&self.alias, alias.len()));
         Ok(())
     }
 
+    /// Sets the device name from a string, truncating at a UTF-8 boundary
+    ///
+    /// If the name is longer than 32 bytes it is cut at the largest character
+    /// boundary that fits, so the stored bytes are always valid UTF-8 and never
+    /// end in a partial multi-byte character.
+    ///
+    /// # Parameters
+    /// - `device_name`: Device name as string slice (any length)
+    ///
+    /// # Returns
+    /// - `Ok(true)` if the name was truncated
+    /// - `Ok(false)` if the whole name was stored
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidBluetoothDeviceInfo` if the truncated name still
+    /// exceeds 32 bytes, which cannot happen for valid UTF-8 input.
+    pub fn set_device_name_utf8_safe(&mut self, device_name: &str) -> Result<bool, Error> {
+        let mut len = device_name.len().min(self.device_name.len());
+        while !device_name.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        self.set_device_name(&device_name.as_bytes()[..len])?;
+        Ok(len < device_name.len())
+    }
+
     /// Sets the pairing key/PIN for the device
     ///
     /// # Parameters
@@ -788,25 +1963,67 @@ impl BluetoothDeviceInfo {
     ///
     /// # Errors
     /// Returns `Error::InvalidBluetoothDeviceInfo` if the pairing key exceeds 64 bytes.
-    #[allow(clippy::cast_possible_truncation)]
     pub fn set_pairing_key(&mut self, pairing_key: &[u8]) -> Result<(), Error> {
-        if pairing_key.len() > 64 {
+        if pairing_key.len() > limits::MAX_PAIRING_KEY_LEN {
             return Err(Error::InvalidBluetoothDeviceInfo);
         }
 
-        self.pairing_key_len = pairing_key.len() as u8;
-        self.pairing_key.fill(0);
-        self.pairing_key[..pairing_key.len()].copy_from_slice(pairing_key);
+        self.set_pairing_key_truncating(pairing_key);
         Ok(())
     }
 
+    /// Sets the pairing key/PIN, truncating it to 64 bytes if necessary
+    ///
+    /// # Parameters
+    /// - `pairing_key`: Pairing key/PIN as byte slice (any length)
+    ///
+    /// # Returns
+    /// - `true` if the key was longer than 64 bytes and got truncated
+    /// - `false` if the whole key was stored
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn set_pairing_key_truncating(&mut self, pairing_key: &[u8]) -> bool {
+        let len = pairing_key.len().min(self.pairing_key.len());
+
+        self.pairing_key_len = len as u8;
+        self.pairing_key.fill(0);
+        self.pairing_key[..len].copy_from_slice(&pairing_key[..len]);
+        debug_assert!(util::tail_is_zero(&self.pairing_key, len));
+        len < pairing_key.len()
+    }
+
     /// Returns the stored pairing key as a byte slice
     ///
     /// # Returns
     /// A slice containing only the valid pairing key bytes (length determined by `pairing_key_len`)
     #[must_use]
     pub fn get_pairing_key(&self) -> &[u8] {
-        &self.pairing_key[..self.pairing_key_len as usize]
+        util::prefix(&self.pairing_key, self.pairing_key_len)
+    }
+
+    /// Returns how many more bytes the pairing key could hold
+    ///
+    /// # Returns
+    /// The pairing key capacity (64 bytes) minus the current key length
+    #[must_use]
+    pub fn pairing_key_remaining(&self) -> usize {
+        self.pairing_key.len() - self.get_pairing_key().len()
+    }
+
+    /// Compares a candidate pairing key/PIN against the stored one
+    ///
+    /// The comparison runs in constant time with respect to the key contents,
+    /// so it does not leak how many leading bytes matched. A length mismatch
+    /// is only reported after the full scan.
+    ///
+    /// # Parameters
+    /// - `candidate`: Pairing key/PIN to verify
+    ///
+    /// # Returns
+    /// - `true` if `candidate` equals the stored pairing key
+    /// - `false` otherwise
+    #[must_use]
+    pub fn pairing_key_eq(&self, candidate: &[u8]) -> bool {
+        util::constant_time_eq(self.get_pairing_key(), candidate)
     }
 
     /// Sets both device name and pairing key at once
@@ -851,7 +2068,7 @@ impl BluetoothDeviceInfo {
     /// Updates connection parameters
     pub fn update_connection_params(&mut self, params: &BluetoothConnectionParams) {
         self.connection_params = *params;
-        self.connection_count += 1;
+        self.increment_connection_count();
         self.add_flag(Self::FLAG_CONNECTED);
     }
 
@@ -863,6 +2080,121 @@ impl BluetoothDeviceInfo {
         }
     }
 
+    /// Checks whether the stored security information meets a security policy
+    ///
+    /// # Parameters
+    /// - `min_level`: Minimum required security level (0x01-0x04)
+    /// - `require_mitm`: Whether MITM protection must have been negotiated
+    /// - `require_encryption`: Whether the connection must be encrypted
+    ///
+    /// # Returns
+    /// - `true` if the stored security level is at least `min_level` and every
+    ///   required protection is recorded in the security information
+    /// - `false` otherwise
+    ///
+    /// # Note
+    /// MITM protection is read from the negotiated `AuthRequirements::MITM`
+    /// bit, not from `mitm_required`, which only records that it was
+    /// requested. Unrecognized authentication requirement bits fail the
+    /// MITM check.
+    #[must_use]
+    pub fn meets_security_policy(
+        &self,
+        min_level: u8,
+        require_mitm: bool,
+        require_encryption: bool,
+    ) -> bool {
+        let security = &self.security_info;
+
+        security.security_level >= min_level
+            && (!require_mitm
+                || security
+                    .auth_requirements_typed()
+                    .is_ok_and(|auth| auth.contains(AuthRequirements::MITM)))
+            && (!require_encryption || security.encrypted != 0)
+    }
+
+    /// Stores the peripheral-preferred connection parameters
+    ///
+    /// # Parameters
+    /// - `ppcp`: Parameters read from the peripheral's PPCP characteristic
+    pub fn set_ppcp(&mut self, ppcp: &PeripheralPreferredParams) {
+        self.ppcp = *ppcp;
+        self.ppcp_valid = 1;
+    }
+
+    /// Returns the stored peripheral-preferred connection parameters
+    ///
+    /// # Returns
+    /// - `Some(&PeripheralPreferredParams)` if parameters have been stored
+    /// - `None` otherwise
+    #[must_use]
+    pub fn get_ppcp(&self) -> Option<&PeripheralPreferredParams> {
+        (self.ppcp_valid != 0).then_some(&self.ppcp)
+    }
+
+    /// Discards the stored peripheral-preferred connection parameters
+    pub fn clear_ppcp(&mut self) {
+        self.ppcp = PeripheralPreferredParams::default();
+        self.ppcp_valid = 0;
+    }
+
+    /// Checks whether connection parameters honor the peripheral's preferences
+    ///
+    /// The connection interval must lie within the preferred range, the
+    /// latency must not exceed the preferred latency, and the supervision
+    /// timeout must not be shorter than the preferred timeout. Fields set to
+    /// `PeripheralPreferredParams::NO_PREFERENCE` are not checked.
+    ///
+    /// # Parameters
+    /// - `params`: Connection parameters to check
+    ///
+    /// # Returns
+    /// - `true` if the parameters satisfy the stored preferences, or none are stored
+    /// - `false` otherwise
+    #[must_use]
+    pub fn satisfies_ppcp(&self, params: &BluetoothConnectionParams) -> bool {
+        let Some(ppcp) = self.get_ppcp() else {
+            return true;
+        };
+        let unset = |value: u16| value == PeripheralPreferredParams::NO_PREFERENCE;
+
+        (unset(ppcp.min_interval) || params.connection_interval >= ppcp.min_interval)
+            && (unset(ppcp.max_interval) || params.connection_interval <= ppcp.max_interval)
+            && (unset(ppcp.latency) || params.connection_latency <= ppcp.latency)
+            && (unset(ppcp.timeout) || params.supervision_timeout >= ppcp.timeout)
+    }
+
+    /// Records the audio codecs the peer supports
+    ///
+    /// Only meaningful for devices of type `DEVICE_TYPE_AUDIO`. Replaces any
+    /// previously recorded set.
+    ///
+    /// # Parameters
+    /// - `codecs`: Supported codecs, e.g. `Codec::SBC | Codec::AAC`
+    pub fn set_codec_support(&mut self, codecs: Codec) {
+        self.supported_codecs = codecs.bits();
+    }
+
+    /// Returns the recorded audio codec support
+    ///
+    /// # Returns
+    /// The supported codecs, empty if unknown
+    #[must_use]
+    pub fn codec_support(&self) -> Codec {
+        Codec(self.supported_codecs & Codec::VALID_MASK)
+    }
+
+    /// Checks whether the peer is known to support a codec
+    ///
+    /// # Returns
+    /// - `true` if every codec in `codec` was recorded as supported
+    /// - `false` otherwise, including when support is unknown
+    #[must_use]
+    pub fn supports_codec(&self, codec: Codec) -> bool {
+        !codec.is_empty() && self.codec_support().contains(codec)
+    }
+
     /// Sets connection flags
     pub fn set_flags(&mut self, flags: u8) {
         self.flags = flags;
@@ -904,6 +2236,31 @@ impl BluetoothDeviceInfo {
         self.connection_count = self.connection_count.saturating_add(1);
     }
 
+    /// Records a connection attempt
+    ///
+    /// Call once per attempt, before its outcome is known. Successful
+    /// attempts are counted separately via the connection count.
+    pub fn record_attempt(&mut self) {
+        self.attempt_count = self.attempt_count.saturating_add(1);
+    }
+
+    /// Returns the connection success rate in permille
+    ///
+    /// # Returns
+    /// Successful connections per 1000 attempts, capped at 1000, or 0 if no
+    /// attempt has been recorded
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn success_rate_permille(&self) -> u16 {
+        if self.attempt_count == 0 {
+            return 0;
+        }
+
+        let rate = u64::from(self.connection_count) * 1000 / u64::from(self.attempt_count);
+        // Safe cast: the rate is capped at 1000
+        rate.min(1000) as u16
+    }
+
     /// Sets the last connected timestamp
     pub fn set_last_connected(&mut self, timestamp: u32) {
         self.last_connected = timestamp;
@@ -914,15 +2271,160 @@ impl BluetoothDeviceInfo {
         self.last_seen = timestamp;
     }
 
+    /// Records that the device has been seen
+    ///
+    /// # Parameters
+    /// - `now`: Current time in seconds since epoch
+    pub fn touch_seen(&mut self, now: u32) {
+        self.last_seen = now;
+    }
+
+    /// Records a completed connection to the device
+    ///
+    /// Updates both the last connected and last seen timestamps, increments
+    /// the connection count and sets `FLAG_CONNECTED`.
+    ///
+    /// # Parameters
+    /// - `now`: Current time in seconds since epoch
+    pub fn touch_connected(&mut self, now: u32) {
+        self.last_connected = now;
+        self.last_seen = now;
+        self.increment_connection_count();
+        self.add_flag(Self::FLAG_CONNECTED);
+    }
+
+    /// Returns the seconds elapsed since the device was last connected
+    ///
+    /// # Parameters
+    /// - `now`: Current time in seconds since epoch
+    ///
+    /// # Returns
+    /// - `Some(u32)` with the elapsed time, 0 if `now` is before the timestamp
+    /// - `None` if the device has never been connected (timestamp 0)
+    #[must_use]
+    pub fn time_since_last_connected(&self, now: u32) -> Option<u32> {
+        (self.last_connected != 0).then(|| now.saturating_sub(self.last_connected))
+    }
+
+    /// Returns the seconds elapsed since the device was last seen
+    ///
+    /// # Parameters
+    /// - `now`: Current time in seconds since epoch
+    ///
+    /// # Returns
+    /// - `Some(u32)` with the elapsed time, 0 if `now` is before the timestamp
+    /// - `None` if the device has never been seen (timestamp 0)
+    #[must_use]
+    pub fn time_since_last_seen(&self, now: u32) -> Option<u32> {
+        (self.last_seen != 0).then(|| now.saturating_sub(self.last_seen))
+    }
+
     /// Getters
     #[must_use]
     pub fn get_mac_address(&self) -> &[u8; 6] {
         &self.mac_address
     }
 
+    /// Returns an owned copy of the MAC address
+    #[must_use]
+    pub fn mac_address(&self) -> [u8; 6] {
+        self.mac_address
+    }
+
+    /// Returns the MAC address expanded to EUI-64 form
+    ///
+    /// The 48-bit address is split between its OUI (first three bytes) and
+    /// the device-specific part, with `0xFF 0xFE` inserted in between.
+    ///
+    /// # Note
+    /// This is the plain IEEE mapping. Deriving an IPv6 interface identifier
+    /// (modified EUI-64) additionally requires inverting the universal/local
+    /// bit (0x02 of the first byte).
+    #[must_use]
+    pub fn eui64(&self) -> [u8; 8] {
+        let mut eui64 = [0xFF; 8];
+        eui64[..3].copy_from_slice(&self.mac_address[..3]);
+        eui64[4] = 0xFE;
+        eui64[5..].copy_from_slice(&self.mac_address[3..]);
+        eui64
+    }
+
+    /// Converts an EUI-64 identifier back to a 48-bit MAC address
+    ///
+    /// This is the inverse of `eui64`: the two middle bytes are dropped
+    /// without being checked.
+    #[must_use]
+    pub fn from_eui64(eui64: [u8; 8]) -> [u8; 6] {
+        let mut mac_address = [0; 6];
+        mac_address[..3].copy_from_slice(&eui64[..3]);
+        mac_address[3..].copy_from_slice(&eui64[5..]);
+        mac_address
+    }
+
     #[must_use]
     pub fn get_device_name(&self) -> &[u8] {
-        &self.device_name[..self.device_name_len as usize]
+        util::prefix(&self.device_name, self.device_name_len)
+    }
+
+    /// Returns how many more bytes the device name could hold
+    ///
+    /// # Returns
+    /// The device name capacity (32 bytes) minus the current name length
+    #[must_use]
+    pub fn name_remaining(&self) -> usize {
+        self.device_name.len() - self.get_device_name().len()
+    }
+
+    #[must_use]
+    pub fn get_alias(&self) -> &[u8] {
+        util::prefix(&self.alias, self.alias_len)
+    }
+
+    /// Writes a label for the device into a caller buffer
+    ///
+    /// The label is `display_name` if it is non-empty, otherwise the MAC
+    /// address formatted as `12:34:56:78:9A:BC` (17 bytes, uppercase hex, in
+    /// stored byte order).
+    ///
+    /// # Parameters
+    /// - `out`: Destination buffer
+    ///
+    /// # Returns
+    /// - `Ok(usize)` with the number of bytes written
+    /// - `Err(Error)` if `out` is too small
+    ///
+    /// # Errors
+    /// Returns `Error::BufferTooSmall` if `out` is shorter than the label.
+    pub fn display_name_or_mac(&self, out: &mut [u8]) -> Result<usize, Error> {
+        let name = self.display_name();
+        if !name.is_empty() {
+            out.get_mut(..name.len())
+                .ok_or(Error::BufferTooSmall)?
+                .copy_from_slice(name);
+            return Ok(name.len());
+        }
+
+        let out = out.get_mut(..17).ok_or(Error::BufferTooSmall)?;
+        for (i, &b) in self.mac_address.iter().enumerate() {
+            out[i * 3..i * 3 + 2].copy_from_slice(&util::hex_byte(b));
+            if i < 5 {
+                out[i * 3 + 2] = b':';
+            }
+        }
+        Ok(out.len())
+    }
+
+    /// Returns the name to show to the user
+    ///
+    /// # Returns
+    /// The alias if one is set, otherwise the advertised device name
+    #[must_use]
+    pub fn display_name(&self) -> &[u8] {
+        if self.alias_len == 0 {
+            self.get_device_name()
+        } else {
+            self.get_alias()
+        }
     }
 
     #[must_use]
@@ -1000,12 +2502,16 @@ impl BluetoothDeviceInfo {
 ///
 /// // Handles can be converted to/from u16
 /// let raw_value: u16 = handle.into();
-/// let back_to_handle = ConnHandle::from(raw_value);
+/// let back_to_handle = ConnHandle::try_from(raw_value)?;
 /// assert_eq!(handle, back_to_handle);
 ///
 /// // Maximum valid handle
 /// let max_handle = ConnHandle::new(0x0EFF);
 /// assert_eq!(max_handle.raw(), 0x0EFF);
+///
+/// // Untrusted values are checked without panicking
+/// assert!(ConnHandle::try_new(0x0F00).is_err());
+/// # Ok::<(), renik::Error>(())
 /// ```
 ///
 /// # Panics
@@ -1015,6 +2521,9 @@ impl BluetoothDeviceInfo {
 /// let invalid = ConnHandle::new(0x0F00); // Panics!
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16", into = "u16"))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(transparent)]
 #[derive(Default)]
 pub struct ConnHandle(u16);
@@ -1027,12 +2536,51 @@ impl ConnHandle {
     ///
     /// # Panics
     /// Panics if the value exceeds 0x0EFF (the maximum valid connection handle).
+    /// Use `try_new` for values from untrusted sources.
     #[must_use]
     pub fn new(val: u16) -> Self {
-        assert!(val <= 0x0EFF, "Connection handle must be <= 0x0EFF");
+        assert!(
+            Self::is_valid_raw(val),
+            "Connection handle must be <= 0x0EFF"
+        );
         Self(val)
     }
 
+    /// Create a new connection handle instance without panicking.
+    ///
+    /// # Parameters
+    /// - `val`: Raw connection handle value, e.g. read from an HCI event
+    ///
+    /// # Returns
+    /// - `Ok(ConnHandle)` if the value is at most 0x0EFF
+    /// - `Err(Error)` otherwise
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidConnectionHandle` if the value lies in the
+    /// reserved range 0x0F00-0xFFFF.
+    pub fn try_new(val: u16) -> Result<Self, Error> {
+        if Self::is_valid_raw(val) {
+            Ok(Self(val))
+        } else {
+            Err(Error::InvalidConnectionHandle)
+        }
+    }
+
+    /// Checks whether a raw value is a valid connection handle
+    ///
+    /// Usable in const contexts and match guards.
+    ///
+    /// # Parameters
+    /// - `val`: Raw connection handle value
+    ///
+    /// # Returns
+    /// - `true` if `val` is at most 0x0EFF
+    /// - `false` for the reserved range 0x0F00-0xFFFF
+    #[must_use]
+    pub const fn is_valid_raw(val: u16) -> bool {
+        val <= limits::MAX_CONN_HANDLE
+    }
+
     /// Get the underlying representation.
     ///
     /// # Returns
@@ -1043,9 +2591,11 @@ impl ConnHandle {
     }
 }
 
-impl From<u16> for ConnHandle {
-    fn from(val: u16) -> Self {
-        Self::new(val)
+impl TryFrom<u16> for ConnHandle {
+    type Error = Error;
+
+    fn try_from(val: u16) -> Result<Self, Error> {
+        Self::try_new(val)
     }
 }
 
@@ -1055,11 +2605,284 @@ impl From<ConnHandle> for u16 {
     }
 }
 
+/// Received signal strength indicator in dBm
+///
+/// Wraps the raw `i8` RSSI stored in `BluetoothConnectionParams` and gives
+/// the -127 sentinel a name, so "no measurement" cannot be mistaken for a
+/// very weak signal.
+///
+/// Ordering follows signal strength, with `Rssi::UNKNOWN` ranking below every
+/// measured value.
+///
+/// # Examples
+/// ```
+/// use renik::Rssi;
+///
+/// let near = Rssi::from(-40);
+/// let far = Rssi::from(-90);
+/// assert!(near > far);
+/// assert_eq!(near.dbm(), Some(-40));
+///
+/// assert!(!Rssi::UNKNOWN.is_known());
+/// assert_eq!(Rssi::UNKNOWN.dbm(), None);
+/// assert!(Rssi::UNKNOWN < far);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(transparent)]
+pub struct Rssi(i8);
+
+impl Rssi {
+    /// Sentinel value used when no RSSI measurement is available
+    pub const UNKNOWN: Self = Self(-127);
+
+    /// Checks whether this is a real measurement
+    ///
+    /// # Returns
+    /// - `true` if the value is not `Rssi::UNKNOWN`
+    /// - `false` otherwise
+    #[must_use]
+    pub fn is_known(self) -> bool {
+        self != Self::UNKNOWN
+    }
+
+    /// Returns the signal strength in dBm
+    ///
+    /// # Returns
+    /// - `Some(i8)` with the measured value
+    /// - `None` if the RSSI is unknown
+    #[must_use]
+    pub fn dbm(self) -> Option<i8> {
+        self.is_known().then_some(self.0)
+    }
+
+    /// Get the underlying representation.
+    ///
+    /// # Returns
+    /// The raw i8 value, -127 when unknown.
+    #[must_use]
+    pub fn raw(self) -> i8 {
+        self.0
+    }
+}
+
+impl Default for Rssi {
+    fn default() -> Self {
+        Self::UNKNOWN
+    }
+}
+
+impl Ord for Rssi {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (self.is_known(), self.0).cmp(&(other.is_known(), other.0))
+    }
+}
+
+impl PartialOrd for Rssi {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<i8> for Rssi {
+    fn from(val: i8) -> Self {
+        Self(val)
+    }
+}
+
+impl From<Rssi> for i8 {
+    fn from(rssi: Rssi) -> Self {
+        rssi.raw()
+    }
+}
+
+/// Authentication requirement flags
+///
+/// Follows the layout of the Security Manager `AuthReq` field. Bits outside
+/// the named flags are reserved; values carrying them cannot be constructed,
+/// so a malformed byte never reaches the controller.
+///
+/// # Examples
+/// ```
+/// use renik::{AuthRequirements, BluetoothSecurityInfo};
+///
+/// let mut security = BluetoothSecurityInfo::default();
+/// security.set_auth_requirements_typed(AuthRequirements::BONDING | AuthRequirements::MITM);
+/// assert_eq!(security.auth_requirements, 0x05);
+///
+/// assert!(AuthRequirements::from_bits(0x80).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(transparent)]
+pub struct AuthRequirements(u8);
+
+impl AuthRequirements {
+    /// Bonding requested
+    pub const BONDING: Self = Self(0x01);
+    /// Man-in-the-middle protection required
+    pub const MITM: Self = Self(0x04);
+    /// LE Secure Connections pairing requested
+    pub const SECURE_CONNECTIONS: Self = Self(0x08);
+    /// Keypress notifications requested during passkey entry
+    pub const KEYPRESS: Self = Self(0x10);
+    /// Cross-transport key derivation (h7) supported
+    pub const CT2: Self = Self(0x20);
+
+    /// Mask of all non-reserved bits
+    const VALID_MASK: u8 = 0x3D;
+
+    /// Returns requirements with no flags set
+    #[must_use]
+    pub fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Creates requirements from a raw byte
+    ///
+    /// # Parameters
+    /// - `bits`: Raw authentication requirements byte
+    ///
+    /// # Returns
+    /// - `Ok(AuthRequirements)` if only defined bits are set
+    /// - `Err(Error)` if reserved bits are set
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidCredentialFormat` if any reserved bit is set.
+    pub fn from_bits(bits: u8) -> Result<Self, Error> {
+        if bits & !Self::VALID_MASK != 0 {
+            return Err(Error::InvalidCredentialFormat);
+        }
+        Ok(Self(bits))
+    }
+
+    /// Get the underlying representation.
+    ///
+    /// # Returns
+    /// The raw authentication requirements byte.
+    #[must_use]
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Checks whether all flags in `other` are set
+    #[must_use]
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Sets the flags in `other`
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    /// Clears the flags in `other`
+    pub fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+}
+
+impl core::ops::BitOr for AuthRequirements {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for AuthRequirements {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.insert(rhs);
+    }
+}
+
+impl TryFrom<u8> for AuthRequirements {
+    type Error = Error;
+
+    fn try_from(bits: u8) -> Result<Self, Error> {
+        Self::from_bits(bits)
+    }
+}
+
+impl From<AuthRequirements> for u8 {
+    fn from(requirements: AuthRequirements) -> Self {
+        requirements.bits()
+    }
+}
+
+/// Audio codec flags for `BluetoothDeviceInfo::set_codec_support`
+///
+/// # Examples
+/// ```
+/// use renik::{BluetoothDeviceInfo, Codec};
+///
+/// let mut device = BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], b"Speaker")?;
+/// device.set_codec_support(Codec::SBC | Codec::AAC);
+/// assert!(device.supports_codec(Codec::AAC));
+/// assert!(!device.supports_codec(Codec::LDAC));
+/// # Ok::<(), renik::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(transparent)]
+pub struct Codec(u8);
+
+impl Codec {
+    /// Low Complexity Subband Coding, mandatory for A2DP
+    pub const SBC: Self = Self(0x01);
+    /// Advanced Audio Coding
+    pub const AAC: Self = Self(0x02);
+    /// Qualcomm aptX
+    pub const APTX: Self = Self(0x04);
+    /// Sony LDAC
+    pub const LDAC: Self = Self(0x08);
+
+    /// Mask of all defined codec bits
+    const VALID_MASK: u8 = 0x0F;
+
+    /// Returns a set with no codecs
+    #[must_use]
+    pub fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Checks whether no codec is set
+    #[must_use]
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Get the underlying representation.
+    ///
+    /// # Returns
+    /// The raw codec bitfield.
+    #[must_use]
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Checks whether all codecs in `other` are set
+    #[must_use]
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl core::ops::BitOr for Codec {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
 /// Connection phases for multi-phase Bluetooth connection flow
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum BluetoothConnectionPhase {
     /// Initial state - no connection attempt
+    #[default]
     Idle = 0,
     /// Discovering devices
     Discovery = 1,
@@ -1069,31 +2892,56 @@ pub enum BluetoothConnectionPhase {
     Connected = 3,
     /// Authentication in progress
     Authenticating = 4,
+    /// Authenticated, exchanging and storing bond keys
+    Bonding = 5,
     /// Authenticated, setting up encryption
-    SettingUpEncryption = 5,
+    SettingUpEncryption = 6,
     /// Connected, authenticated, and encrypted
-    FullyConnected = 6,
+    FullyConnected = 7,
     /// Service discovery in progress
-    ServiceDiscovery = 7,
+    ServiceDiscovery = 8,
     /// Connection established with services
-    Ready = 8,
+    Ready = 9,
     /// Connection maintenance mode
-    Maintaining = 9,
+    Maintaining = 10,
     /// Connection lost, attempting reconnection
-    Reconnecting = 10,
+    Reconnecting = 11,
     /// Connection failed
-    Failed = 11,
+    Failed = 12,
     /// Disconnecting
-    Disconnecting = 12,
+    Disconnecting = 13,
 }
 
-impl Default for BluetoothConnectionPhase {
-    fn default() -> Self {
-        Self::Idle
+impl TryFrom<u8> for BluetoothConnectionPhase {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Error> {
+        Self::ALL
+            .get(usize::from(value))
+            .copied()
+            .ok_or(Error::InvalidConnectionPhase)
     }
 }
 
 impl BluetoothConnectionPhase {
+    /// Every phase, in numeric order
+    pub const ALL: [Self; limits::PHASE_COUNT] = [
+        Self::Idle,
+        Self::Discovery,
+        Self::Connecting,
+        Self::Connected,
+        Self::Authenticating,
+        Self::Bonding,
+        Self::SettingUpEncryption,
+        Self::FullyConnected,
+        Self::ServiceDiscovery,
+        Self::Ready,
+        Self::Maintaining,
+        Self::Reconnecting,
+        Self::Failed,
+        Self::Disconnecting,
+    ];
+
     /// Returns true if the phase indicates an active connection
     #[must_use]
     pub fn is_connected(&self) -> bool {
@@ -1101,6 +2949,7 @@ impl BluetoothConnectionPhase {
             self,
             Self::Connected
                 | Self::Authenticating
+                | Self::Bonding
                 | Self::SettingUpEncryption
                 | Self::FullyConnected
                 | Self::ServiceDiscovery
@@ -1123,4 +2972,90 @@ impl BluetoothConnectionPhase {
     pub fn is_ready(&self) -> bool {
         matches!(self, Self::Ready | Self::Maintaining)
     }
+
+    /// Returns the minimal number of transitions from this phase to `Ready`
+    ///
+    /// The distance is computed with a breadth-first search over the same
+    /// transition table `advance_to_phase` enforces. Paths through `Failed` or
+    /// `Disconnecting` are not considered progress, and the reset to `Idle`
+    /// is not followed.
+    ///
+    /// # Returns
+    /// - `Some(0)` for phases where `is_ready` holds
+    /// - `Some(steps)` with the length of the shortest path to `Ready`
+    /// - `None` for `Failed` and `Disconnecting`
+    ///
+    /// # Examples
+    /// ```
+    /// use renik::BluetoothConnectionPhase;
+    ///
+    /// assert_eq!(BluetoothConnectionPhase::Idle.steps_to_ready(), Some(4));
+    /// assert_eq!(BluetoothConnectionPhase::Ready.steps_to_ready(), Some(0));
+    /// assert_eq!(BluetoothConnectionPhase::Failed.steps_to_ready(), None);
+    /// ```
+    #[must_use]
+    pub fn steps_to_ready(&self) -> Option<u8> {
+        const fn bit(phase: BluetoothConnectionPhase) -> u16 {
+            1 << phase as u8
+        }
+        let dead_ends = bit(Self::Failed) | bit(Self::Disconnecting);
+
+        if bit(*self) & dead_ends != 0 {
+            return None;
+        }
+        if self.is_ready() {
+            return Some(0);
+        }
+
+        let mut visited = bit(*self);
+        let mut frontier = visited;
+        let mut steps = 0;
+        while frontier != 0 {
+            if frontier & bit(Self::Ready) != 0 {
+                return Some(steps);
+            }
+
+            let mut next = 0;
+            for (from, to) in all_valid_transitions() {
+                if frontier & bit(from) != 0 && to != Self::Idle {
+                    next |= bit(to);
+                }
+            }
+            frontier = next & !visited & !dead_ends;
+            visited |= frontier;
+            steps += 1;
+        }
+
+        None
+    }
+
+    /// Returns the severity category of the phase
+    ///
+    /// # Returns
+    /// - `Severity::Error` for `Failed`
+    /// - `Severity::Warn` for `Reconnecting` and `Disconnecting`
+    /// - `Severity::Info` for every other phase
+    #[must_use]
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::Failed => Severity::Error,
+            Self::Reconnecting | Self::Disconnecting => Severity::Warn,
+            _ => Severity::Info,
+        }
+    }
+}
+
+/// Severity category of a connection phase
+///
+/// Returned by [`BluetoothConnectionPhase::severity`] for picking a log level
+/// or status LED color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Severity {
+    /// Normal operation or progress
+    Info,
+    /// Degraded, but recovering or shutting down in an orderly way
+    Warn,
+    /// The connection failed
+    Error,
 }
@@ -1,4 +1,4 @@
-use crate::Error;
+use crate::{Error, limits, util};
 use bytemuck::{Pod, Zeroable};
 
 /// Magic number used to validate device information structures
@@ -23,14 +23,27 @@ const DEVICE_INFO_MAGIC: u32 = 0x0044_4556;
 /// assert!(config.is_valid());
 /// ```
 #[derive(Debug, Clone, Copy, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct DeviceInfo {
     /// Magic number for structure validation (0x444556)
     magic: u32, // 4-byte aligned
     /// Unique hardware identifier (16 bytes)
-    hardware_id: [u8; 32], // 1-byte aligned
+    hardware_id: [u8; limits::MAX_HARDWARE_ID_LEN], // 1-byte aligned
     /// Device secret (128 bytes)
-    secret: [u8; 128], // 1-byte aligned
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    secret: [u8; limits::MAX_SECRET_LEN], // 1-byte aligned
+    /// Lock flag (0 = writable, 1 = locked after provisioning)
+    locked: u8, // 1-byte aligned
+    /// Actual length of the hardware identifier (0-32 bytes)
+    hardware_id_len: u8, // 1-byte aligned
+    /// Actual length of the device secret (0-128 bytes)
+    secret_len: u8, // 1-byte aligned
+    /// Padding to ensure proper alignment
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _padding: [u8; 1], // Ensures 4-byte alignment
+    /// CRC-32 of all preceding bytes, set by `finalize`
+    crc32: u32, // 4-byte aligned
 }
 
 impl Default for DeviceInfo {
@@ -41,8 +54,13 @@ impl Default for DeviceInfo {
     fn default() -> Self {
         Self {
             magic: DEVICE_INFO_MAGIC,
-            hardware_id: [0; 32],
-            secret: [0; 128],
+            hardware_id: [0; limits::MAX_HARDWARE_ID_LEN],
+            secret: [0; limits::MAX_SECRET_LEN],
+            locked: 0,
+            hardware_id_len: 0,
+            secret_len: 0,
+            _padding: [0; 1],
+            crc32: 0,
         }
     }
 }
@@ -68,6 +86,79 @@ impl DeviceInfo {
         Ok(di)
     }
 
+    /// Creates an all-zero device information structure with only the magic number set
+    ///
+    /// Equivalent to `default()` for the current layout, but guaranteed to
+    /// stay all-zero should non-zero defaults be introduced.
+    #[must_use]
+    pub fn new_zeroed_with_magic() -> Self {
+        Self {
+            magic: DEVICE_INFO_MAGIC,
+            ..Zeroable::zeroed()
+        }
+    }
+
+    /// Reads device information from a byte slice
+    ///
+    /// Intended for loading the identity back from flash. The slice must
+    /// hold exactly one structure but does not need to be aligned. The
+    /// checksum is not checked; call `verify` for that.
+    ///
+    /// # Parameters
+    /// - `bytes`: Serialized device information
+    ///
+    /// # Returns
+    /// - `Ok(DeviceInfo)` if the bytes hold a valid structure
+    /// - `Err(Error)` if the slice has the wrong length or the data is invalid
+    ///
+    /// # Errors
+    /// Returns `Error::CorruptData` if the slice length is not exactly
+    /// `size_of::<DeviceInfo>()`, the magic number does not match, or a
+    /// stored length exceeds its buffer.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let info: Self = bytemuck::try_pod_read_unaligned(bytes).map_err(|_| Error::CorruptData)?;
+        info.check_stored()?;
+        Ok(info)
+    }
+
+    /// Reads device information from a byte slice, ignoring trailing bytes
+    ///
+    /// Unlike `from_bytes`, longer slices are accepted. Only the first
+    /// `size_of::<DeviceInfo>()` bytes are read; the slice does not need to
+    /// be aligned.
+    ///
+    /// # Parameters
+    /// - `bytes`: Serialized device information, at least as long as the
+    ///   structure
+    ///
+    /// # Returns
+    /// - `Ok(DeviceInfo)` if the bytes hold a valid structure
+    /// - `Err(Error)` if the slice is too short or the data is invalid
+    ///
+    /// # Errors
+    /// Returns `Error::CorruptData` if the slice is shorter than the structure,
+    /// the magic number does not match, or a stored length exceeds its buffer.
+    pub fn from_bytes_relaxed(bytes: &[u8]) -> Result<Self, Error> {
+        let bytes = bytes
+            .get(..core::mem::size_of::<Self>())
+            .ok_or(Error::CorruptData)?;
+        let info: Self = bytemuck::pod_read_unaligned(bytes);
+        info.check_stored()?;
+        Ok(info)
+    }
+
+    /// Checks the magic number and length fields of data read from storage
+    fn check_stored(&self) -> Result<(), Error> {
+        if !self.is_valid()
+            || usize::from(self.hardware_id_len) > self.hardware_id.len()
+            || usize::from(self.secret_len) > self.secret.len()
+        {
+            return Err(Error::CorruptData);
+        }
+
+        Ok(())
+    }
+
     /// Validates the device information structure
     ///
     /// # Returns
@@ -78,6 +169,20 @@ impl DeviceInfo {
         self.magic == DEVICE_INFO_MAGIC
     }
 
+    /// Checks whether the device identity has been provisioned
+    ///
+    /// # Returns
+    /// - `true` if both the hardware ID and the secret are non-empty
+    /// - `false` otherwise
+    ///
+    /// # Note
+    /// Unlike `is_valid`, this distinguishes a provisioned identity from a
+    /// freshly defaulted one.
+    #[must_use]
+    pub fn is_provisioned(&self) -> bool {
+        !self.get_hardware_id().is_empty() && !self.get_secret().is_empty()
+    }
+
     /// Sets the hardware identifier
     ///
     /// # Parameters
@@ -88,17 +193,26 @@ impl DeviceInfo {
     /// - `Err(Error)` if the hardware ID length exceeded the maximum allowed.
     ///
     /// # Errors
-    /// Returns `Error::IdentityLengthExceeded` if the hardware ID exceeds 32 bytes.
+    /// Returns `Error::ConfigLocked` if the structure has been locked, or
+    /// `Error::IdentityLengthExceeded` if the hardware ID exceeds 32 bytes.
     ///
     /// # Note
-    /// If the input is shorter than 32 bytes, only the specified bytes
-    /// are updated, leaving the remainder unchanged.
+    /// The whole buffer is cleared first, so no bytes of a longer previous
+    /// identifier remain.
+    #[allow(clippy::cast_possible_truncation)]
     pub fn set_hardware_id(&mut self, hardware_id: &[u8]) -> Result<(), Error> {
-        if hardware_id.len() > 32 {
+        if self.is_locked() {
+            return Err(Error::ConfigLocked);
+        }
+        if hardware_id.len() > limits::MAX_HARDWARE_ID_LEN {
             return Err(Error::IdentityLengthExceeded);
         }
 
+        // Safe cast: we've already validated the length is within u8 range
+        self.hardware_id_len = hardware_id.len() as u8;
+        self.hardware_id.fill(0);
         self.hardware_id[..hardware_id.len()].copy_from_slice(hardware_id);
+        debug_assert!(util::tail_is_zero(&self.hardware_id, hardware_id.len()));
         Ok(())
     }
 
@@ -112,35 +226,219 @@ impl DeviceInfo {
     /// - `Err(Error)` if the hardware secret length exceeded the maximum allowed.
     ///
     /// # Errors
-    /// Returns `Error::IdentityLengthExceeded` if the secret exceeds 128 bytes.
+    /// Returns `Error::ConfigLocked` if the structure has been locked, or
+    /// `Error::IdentityLengthExceeded` if the secret exceeds 128 bytes.
     ///
     /// # Note
-    /// If the input is shorter than 128 bytes, only the specified bytes
-    /// are updated, leaving the remainder unchanged.
+    /// The whole buffer is cleared first, so no bytes of a longer previous
+    /// secret remain.
+    #[allow(clippy::cast_possible_truncation)]
     pub fn set_secret(&mut self, secret: &[u8]) -> Result<(), Error> {
-        if secret.len() > 128 {
+        if self.is_locked() {
+            return Err(Error::ConfigLocked);
+        }
+        if secret.len() > limits::MAX_SECRET_LEN {
             return Err(Error::IdentityLengthExceeded);
         }
 
+        // Safe cast: we've already validated the length is within u8 range
+        self.secret_len = secret.len() as u8;
+        self.secret.fill(0);
         self.secret[..secret.len()].copy_from_slice(secret);
+        debug_assert!(util::tail_is_zero(&self.secret, secret.len()));
         Ok(())
     }
 
+    /// Replaces the device secret and returns the previous one
+    ///
+    /// Like `set_secret`, the whole secret buffer is replaced: bytes past
+    /// the end of `new_secret` are cleared. Nothing is modified if the call
+    /// fails, and the returned buffer allows the caller to restore the old
+    /// secret if persisting the new one fails.
+    ///
+    /// # Parameters
+    /// - `new_secret`: New device secret as byte slice (max 128 bytes)
+    ///
+    /// # Returns
+    /// - `Ok([u8; 128])` with a copy of the previous secret buffer
+    /// - `Err(Error)` if the structure is locked or the secret is too long
+    ///
+    /// # Errors
+    /// Returns `Error::ConfigLocked` if the structure has been locked, or
+    /// `Error::IdentityLengthExceeded` if the secret exceeds 128 bytes.
+    ///
+    /// # Note
+    /// The returned buffer holds key material. Callers must zeroize it once
+    /// the rotation has been committed or rolled back. It does not carry the
+    /// previous length; read `secret_len` before rotating to restore the
+    /// exact secret.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn rotate_secret(&mut self, new_secret: &[u8]) -> Result<[u8; 128], Error> {
+        if self.is_locked() {
+            return Err(Error::ConfigLocked);
+        }
+        if new_secret.len() > limits::MAX_SECRET_LEN {
+            return Err(Error::IdentityLengthExceeded);
+        }
+
+        let old_secret = self.secret;
+        // Safe cast: we've already validated the length is within u8 range
+        self.secret_len = new_secret.len() as u8;
+        self.secret.fill(0);
+        self.secret[..new_secret.len()].copy_from_slice(new_secret);
+        debug_assert!(util::tail_is_zero(&self.secret, new_secret.len()));
+        Ok(old_secret)
+    }
+
+    /// Locks the device information against further modification
+    ///
+    /// Once locked, `set_hardware_id`, `set_secret` and `rotate_secret`
+    /// return `Error::ConfigLocked`. The lock flag is part of the structure and
+    /// persists when it is serialized.
+    pub fn lock(&mut self) {
+        self.locked = 1;
+    }
+
+    /// Returns whether the device information is locked
+    ///
+    /// # Returns
+    /// - `true` if `lock` has been called
+    /// - `false` otherwise
+    #[must_use]
+    pub fn is_locked(&self) -> bool {
+        self.locked != 0
+    }
+
     /// Returns the stored hardware identifier
     ///
     /// # Returns
-    /// A reference to the complete 32-byte hardware identifier array
+    /// A slice containing only the valid hardware ID bytes (length determined by `hardware_id_len`)
     #[must_use]
     pub fn get_hardware_id(&self) -> &[u8] {
-        &self.hardware_id
+        util::prefix(&self.hardware_id, self.hardware_id_len)
     }
 
     /// Returns the stored device secret
     ///
     /// # Returns
-    /// A reference to the complete 128-byte secret array
+    /// A slice containing only the valid secret bytes (length determined by `secret_len`)
     #[must_use]
     pub fn get_secret(&self) -> &[u8] {
-        &self.secret
+        util::prefix(&self.secret, self.secret_len)
+    }
+
+    /// Returns the length of the stored hardware identifier
+    ///
+    /// # Returns
+    /// The hardware ID length in bytes (0-32)
+    #[must_use]
+    pub fn hardware_id_len(&self) -> usize {
+        self.get_hardware_id().len()
+    }
+
+    /// Returns the length of the stored device secret
+    ///
+    /// # Returns
+    /// The secret length in bytes (0-128)
+    #[must_use]
+    pub fn secret_len(&self) -> usize {
+        self.get_secret().len()
+    }
+
+    /// Compares the stored hardware identifier against an expected value in constant time
+    ///
+    /// # Parameters
+    /// - `expected`: Expected hardware identifier (max 32 bytes)
+    ///
+    /// # Returns
+    /// - `true` if the stored identifier equals `expected`
+    /// - `false` otherwise, including when `expected` exceeds 32 bytes
+    ///
+    /// # Note
+    /// Only the lengths may short-circuit the comparison; the contents are
+    /// always scanned in full.
+    #[must_use]
+    pub fn matches_hardware_id(&self, expected: &[u8]) -> bool {
+        util::constant_time_eq(self.get_hardware_id(), expected)
+    }
+
+    /// Compares both the hardware identifier and the secret in constant time
+    ///
+    /// Both fields are always compared, so the timing does not reveal which
+    /// one differs.
+    ///
+    /// # Parameters
+    /// - `hardware_id`: Expected hardware identifier (max 32 bytes)
+    /// - `secret`: Expected device secret (max 128 bytes)
+    ///
+    /// # Returns
+    /// - `true` if both fields match
+    /// - `false` otherwise
+    #[must_use]
+    pub fn matches_identity(&self, hardware_id: &[u8], secret: &[u8]) -> bool {
+        let hardware_id_eq = util::constant_time_eq(self.get_hardware_id(), hardware_id);
+        let secret_eq = util::constant_time_eq(self.get_secret(), secret);
+        hardware_id_eq & secret_eq
+    }
+
+    /// Computes the CRC-32 checksum of the structure
+    ///
+    /// Every byte except the stored checksum itself is covered, including
+    /// the magic number, the lock flag and the padding.
+    ///
+    /// # Returns
+    /// The CRC-32 (IEEE 802.3) of the structure
+    #[must_use]
+    pub fn compute_crc(&self) -> u32 {
+        let bytes = bytemuck::bytes_of(self);
+        util::crc32(&bytes[..bytes.len() - core::mem::size_of::<u32>()])
+    }
+
+    /// Stores the checksum of the current contents
+    ///
+    /// Call this after the last modification and before persisting the
+    /// structure. The setters do not update the checksum, so `verify` fails
+    /// until `finalize` is called again. Allowed on a locked structure.
+    pub fn finalize(&mut self) {
+        self.crc32 = self.compute_crc();
+    }
+
+    /// Checks the stored checksum against the current contents
+    ///
+    /// # Returns
+    /// - `true` if the checksum set by `finalize` matches
+    /// - `false` if any byte changed since, e.g. after a partial flash write
+    #[must_use]
+    pub fn verify(&self) -> bool {
+        self.crc32 == self.compute_crc()
+    }
+
+    /// Serializes the public identity for attestation payloads
+    ///
+    /// Writes the magic number (4 bytes, little-endian), the hardware ID
+    /// length (1 byte) and the hardware ID itself. The secret is never
+    /// written.
+    ///
+    /// # Parameters
+    /// - `out`: Destination buffer
+    ///
+    /// # Returns
+    /// - `Ok(usize)` with the number of bytes written
+    /// - `Err(Error)` if `out` is too small
+    ///
+    /// # Errors
+    /// Returns `Error::BufferTooSmall` if `out` cannot hold the identity.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn identity_bytes(&self, out: &mut [u8]) -> Result<usize, Error> {
+        let hardware_id = self.get_hardware_id();
+        let id_len = hardware_id.len();
+        let len = 4 + 1 + id_len;
+        let out = out.get_mut(..len).ok_or(Error::BufferTooSmall)?;
+
+        out[..4].copy_from_slice(&self.magic.to_le_bytes());
+        // Safe cast: the hardware ID is at most 32 bytes
+        out[4] = id_len as u8;
+        out[5..].copy_from_slice(hardware_id);
+        Ok(len)
     }
 }
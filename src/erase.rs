@@ -0,0 +1,140 @@
+//! # Secure Erase
+//!
+//! Uniform wiping of configuration structures for decommissioning.
+
+use crate::{
+    BluetoothConnectionState, BluetoothDeviceInfo, BluetoothDeviceList, BluetoothSecurityInfo,
+    DeviceInfo, WifiConfig, util,
+};
+use bytemuck::Pod;
+
+/// Securely wipes a configuration structure
+///
+/// Implementors overwrite every byte of the structure, including credentials,
+/// secrets, pairing keys and link keys, with zeros using volatile writes that
+/// the optimizer cannot remove, and then reset it to its `Default` state.
+///
+/// # Examples
+/// ```
+/// use renik::{BluetoothDeviceList, DeviceInfo, SecureErase, WifiConfig};
+///
+/// let mut wifi = WifiConfig::new(b"MyNetwork", b"password123")?;
+/// let mut identity = DeviceInfo::new(b"RENIK-01JY1863M2V0S776", b"secret")?;
+/// let mut devices = BluetoothDeviceList::default();
+///
+/// let configs: [&mut dyn SecureErase; 3] = [&mut wifi, &mut identity, &mut devices];
+/// for config in configs {
+///     config.secure_erase();
+/// }
+///
+/// assert_eq!(wifi.get_password(), b"");
+/// # Ok::<(), renik::Error>(())
+/// ```
+pub trait SecureErase {
+    /// Zeroes all data with volatile writes and resets to defaults
+    fn secure_erase(&mut self);
+}
+
+/// Volatile-zeroes the whole structure, then restores its defaults
+fn erase_pod<T: Pod + Default>(value: &mut T) {
+    util::volatile_zero(bytemuck::bytes_of_mut(value));
+    *value = T::default();
+}
+
+impl SecureErase for WifiConfig {
+    fn secure_erase(&mut self) {
+        erase_pod(self);
+    }
+}
+
+impl SecureErase for DeviceInfo {
+    /// Wipes the identity, including a provisioning lock
+    fn secure_erase(&mut self) {
+        erase_pod(self);
+    }
+}
+
+impl SecureErase for BluetoothDeviceInfo {
+    fn secure_erase(&mut self) {
+        erase_pod(self);
+    }
+}
+
+impl SecureErase for BluetoothDeviceList {
+    /// Wipes every slot, including the device kept for `undo_remove`
+    fn secure_erase(&mut self) {
+        erase_pod(self);
+    }
+}
+
+impl SecureErase for BluetoothSecurityInfo {
+    fn secure_erase(&mut self) {
+        erase_pod(self);
+    }
+}
+
+impl SecureErase for BluetoothConnectionState {
+    fn secure_erase(&mut self) {
+        erase_pod(self);
+    }
+}
+
+/// Wrapper that securely erases the wrapped configuration when dropped
+///
+/// The configuration structures are `Pod`, and therefore `Copy`, so they
+/// cannot implement `Drop` themselves. Wrapping a value moves it into a
+/// container that is not `Copy` and calls `SecureErase::secure_erase` on
+/// drop.
+///
+/// # Note
+/// Copies made before the value was wrapped, or taken through `Deref`
+/// (e.g. `*wrapper`), are not erased. Avoid copying the inner value out and
+/// serialize it through `bytemuck::bytes_of(&*wrapper)` instead.
+///
+/// # Examples
+/// ```
+/// use renik::{WifiConfig, ZeroizeOnDrop};
+///
+/// let config = ZeroizeOnDrop::new(WifiConfig::new(b"MyNetwork", b"password123")?);
+/// assert_eq!(config.get_ssid(), b"MyNetwork");
+/// // SSID and password are wiped here
+/// # Ok::<(), renik::Error>(())
+/// ```
+#[cfg(feature = "zeroize")]
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct ZeroizeOnDrop<T: SecureErase>(T);
+
+#[cfg(feature = "zeroize")]
+impl<T: SecureErase> ZeroizeOnDrop<T> {
+    /// Wraps a configuration so it is erased on drop
+    ///
+    /// # Parameters
+    /// - `value`: Configuration to take ownership of
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: SecureErase> core::ops::Deref for ZeroizeOnDrop<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: SecureErase> core::ops::DerefMut for ZeroizeOnDrop<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: SecureErase> Drop for ZeroizeOnDrop<T> {
+    fn drop(&mut self) {
+        self.0.secure_erase();
+    }
+}
@@ -1,7 +1,9 @@
+use crate::BluetoothConnectionPhase;
 use thiserror_no_std::Error;
 
 /// Error type for configuration-related operations
 #[derive(Debug, Error)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Error {
     /// SSID or password length exceeded the maximum allowed
     #[error("SSID or password length exceeded the maximum allowed")]
@@ -18,4 +20,42 @@ pub enum Error {
     /// Index out of bounds
     #[error("Index out of bounds")]
     IndexOutOfBounds,
+    /// Caller-provided output buffer is too small
+    #[error("Output buffer is too small")]
+    BufferTooSmall,
+    /// Credential or security payload could not be parsed
+    #[error("Malformed credential or security payload")]
+    InvalidCredentialFormat,
+    /// No entry matched the lookup
+    #[error("No matching entry found")]
+    NotFound,
+    /// Configuration is locked against modification
+    #[error("Configuration is locked")]
+    ConfigLocked,
+    /// Stored data is internally inconsistent
+    #[error("Configuration data is corrupt or inconsistent")]
+    CorruptData,
+    /// Connection parameters violate the specification limits
+    #[error("Invalid connection parameters")]
+    InvalidConnectionParams,
+    /// Connection handle lies in the reserved range
+    #[error("Connection handle must be <= 0x0EFF")]
+    InvalidConnectionHandle,
+    /// Stored byte does not map to a connection phase
+    #[error("Invalid connection phase")]
+    InvalidConnectionPhase,
+}
+
+/// Error type for rejected Bluetooth connection phase transitions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TransitionError {
+    /// The FSM does not allow moving between the two phases
+    #[error("Illegal connection phase transition from {from:?} to {to:?}")]
+    IllegalTransition {
+        /// Phase the connection was in
+        from: BluetoothConnectionPhase,
+        /// Phase that was requested
+        to: BluetoothConnectionPhase,
+    },
 }
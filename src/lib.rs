@@ -16,6 +16,7 @@
 //!   - `BluetoothConnectionParams`: Low-level connection parameters
 //!   - `BluetoothSecurityInfo`: Security and authentication information
 //! - **Device Identity**: Store device identification and authentication data with `DeviceInfo`
+//! - **Secure Erase**: Wipe any configuration structure with volatile writes via `SecureErase`
 //! - **Memory Safe**: All structures use fixed-size buffers with length tracking
 //! - **Serializable**: `#[repr(C)]` layout for easy persistence and IPC
 //! - **Embedded Ready**: Full `no_std` compatibility with minimal dependencies
@@ -43,13 +44,21 @@
 
 mod bluetooth;
 mod device;
+mod erase;
 mod error;
+pub mod limits;
+mod util;
 mod wifi;
 
 pub use bluetooth::{
-    BluetoothConnectionParams, BluetoothConnectionPhase, BluetoothConnectionState,
-    BluetoothDeviceInfo, BluetoothDeviceList, BluetoothSecurityInfo, ConnHandle,
+    AuthRequirements, BluetoothConnectionParams, BluetoothConnectionParamsBuilder,
+    BluetoothConnectionPhase, BluetoothConnectionState, BluetoothDeviceInfo, BluetoothDeviceList,
+    BluetoothSecurityInfo, Codec, ConnHandle, ConnectionRole, ConnectionSummary,
+    PeripheralPreferredParams, Rssi, Severity, all_valid_transitions, is_valid_unicast_mac,
 };
 pub use device::DeviceInfo;
-pub use error::Error;
-pub use wifi::WifiConfig;
+pub use erase::SecureErase;
+#[cfg(feature = "zeroize")]
+pub use erase::ZeroizeOnDrop;
+pub use error::{Error, TransitionError};
+pub use wifi::{WifiConfig, WifiConfigRef, WifiJoinError, WifiSecurity, derive_hotspot_ssid};
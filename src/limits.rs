@@ -0,0 +1,52 @@
+//! Structural limits enforced by the configuration structures
+//!
+//! These constants are the values the crate itself uses for buffer sizes and
+//! validation, so external tooling can check inputs against them instead of
+//! duplicating the numbers.
+
+/// Maximum number of devices held by a `BluetoothDeviceList`
+pub const MAX_DEVICES: usize = 10;
+
+/// Maximum number of Wi-Fi networks stored
+///
+/// `WifiConfig` holds the credentials of a single network.
+pub const MAX_NETWORKS: usize = 1;
+
+/// Maximum SSID length in bytes
+pub const MAX_SSID_LEN: usize = 32;
+
+/// Maximum Wi-Fi password length in bytes
+pub const MAX_PASSWORD_LEN: usize = 64;
+
+/// Length of a Wi-Fi pre-shared key in bytes
+pub const PSK_LEN: usize = 32;
+
+/// Maximum hardware identifier length in bytes
+pub const MAX_HARDWARE_ID_LEN: usize = 32;
+
+/// Maximum device secret length in bytes
+pub const MAX_SECRET_LEN: usize = 128;
+
+/// Length of a Bluetooth MAC address in bytes
+pub const MAC_ADDRESS_LEN: usize = 6;
+
+/// Maximum Bluetooth device name length in bytes
+pub const MAX_DEVICE_NAME_LEN: usize = 32;
+
+/// Maximum Bluetooth pairing key length in bytes
+pub const MAX_PAIRING_KEY_LEN: usize = 64;
+
+/// Length of a Bluetooth link key in bytes
+pub const LINK_KEY_LEN: usize = 16;
+
+/// Largest valid HCI connection handle
+pub const MAX_CONN_HANDLE: u16 = 0x0EFF;
+
+/// Lowest valid Bluetooth security level
+pub const MIN_SECURITY_LEVEL: u8 = 1;
+
+/// Highest valid Bluetooth security level
+pub const MAX_SECURITY_LEVEL: u8 = 4;
+
+/// Number of phases in the connection state machine
+pub const PHASE_COUNT: usize = 14;
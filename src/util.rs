@@ -0,0 +1,76 @@
+//! Internal helpers shared by the configuration structures
+
+/// Compares two byte slices in constant time with respect to their contents
+///
+/// Every byte up to the longer of the two lengths is inspected, so a length
+/// mismatch is only reported after the full scan. Execution time depends on
+/// the slice lengths only, never on where the first differing byte is.
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    let mut diff = u8::from(a.len() != b.len());
+
+    for i in 0..a.len().max(b.len()) {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+        diff |= x ^ y;
+    }
+
+    core::hint::black_box(diff) == 0
+}
+
+/// Computes the CRC-32 (IEEE 802.3) checksum of a buffer
+///
+/// Bitwise implementation without a lookup table, trading speed for 1KB of
+/// flash; the structures it is used on are only a few hundred bytes.
+pub(crate) fn crc32(buf: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for &b in buf {
+        crc ^= u32::from(b);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+
+    !crc
+}
+
+/// Overwrites a buffer with zeros using volatile writes
+///
+/// Unlike `fill(0)`, the writes cannot be elided by the optimizer even if
+/// the buffer is never read again.
+pub(crate) fn volatile_zero(buf: &mut [u8]) {
+    for b in buf.iter_mut() {
+        // SAFETY: `b` is a valid, aligned and exclusive reference to a `u8`
+        unsafe { core::ptr::write_volatile(b, 0) };
+    }
+
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+}
+
+/// Checks that every byte of `buf` from `len` onwards is zero
+///
+/// Used in debug assertions to enforce that setters never leave stale
+/// secrets behind the effective length of a buffer, and to detect length
+/// fields that are too small for the stored data.
+pub(crate) fn tail_is_zero(buf: &[u8], len: usize) -> bool {
+    buf.get(len..)
+        .is_none_or(|tail| tail.iter().all(|&b| b == 0))
+}
+
+/// Returns the first `len` bytes of `buf`, clamped to the buffer size
+///
+/// Length fields read from untrusted storage may exceed their buffer; this
+/// keeps the accessors panic-free on such data.
+pub(crate) fn prefix(buf: &[u8], len: u8) -> &[u8] {
+    &buf[..usize::from(len).min(buf.len())]
+}
+
+/// Formats a byte as two uppercase hex digits
+pub(crate) fn hex_byte(b: u8) -> [u8; 2] {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+    [
+        HEX_DIGITS[usize::from(b >> 4)],
+        HEX_DIGITS[usize::from(b & 0x0F)],
+    ]
+}
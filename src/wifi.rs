@@ -1,4 +1,4 @@
-use crate::Error;
+use crate::{Error, limits, util};
 use bytemuck::{Pod, Zeroable};
 
 /// Magic number used to validate Wi-Fi configuration structures
@@ -22,21 +22,42 @@ const WIFI_CONFIG_MAGIC: u32 = 0x5749_4649;
 /// let config = WifiConfig::new(b"MyNetwork", b"password123").unwrap();
 /// assert!(config.is_valid());
 /// ```
-#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct WifiConfig {
     /// Magic number for structure validation (0x57494649)
     magic: u32, // 4-byte aligned
     /// Fixed-size buffer for network SSID (maximum 32 bytes)
-    ssid: [u8; 32], // 1-byte aligned
+    ssid: [u8; limits::MAX_SSID_LEN], // 1-byte aligned
     /// Fixed-size buffer for network password (maximum 64 bytes)
-    password: [u8; 64], // 1-byte aligned
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    password: [u8; limits::MAX_PASSWORD_LEN], // 1-byte aligned
     /// Actual length of the SSID (0-32 bytes)
     ssid_len: u8, // 1-byte aligned
     /// Actual length of the password (0-64 bytes)
     password_len: u8, // 1-byte aligned
-    /// Padding to align to a multiple of 4 if needed
-    _padding: [u8; 2], // Ensures no implicit padding
+    /// Preferred frequency band (see the `BAND_*` constants)
+    preferred_band: u8, // 1-byte aligned
+    /// Whether `psk` holds a PSK derived from the current credentials
+    psk_valid: u8, // 1-byte aligned
+    /// Cached 256-bit pre-shared key
+    psk: [u8; limits::PSK_LEN], // 1-byte aligned
+    /// Reason the last join attempt failed (maps to `WifiJoinError`)
+    last_error: u8, // 1-byte aligned
+    /// Lock flag (0 = writable, 1 = locked after provisioning)
+    locked: u8, // 1-byte aligned
+    /// Network security type (maps to `WifiSecurity`)
+    security: u8, // 1-byte aligned
+    /// BSSID of the access point to pin to
+    bssid: [u8; 6], // 1-byte aligned
+    /// Whether `bssid` holds a pinned access point
+    bssid_valid: u8, // 1-byte aligned
+    /// Whether the network does not broadcast its SSID
+    hidden: u8, // 1-byte aligned
+    /// Padding to align to a multiple of 4
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _padding: [u8; 1], // Ensures no implicit padding
 }
 
 impl Default for WifiConfig {
@@ -49,13 +70,89 @@ impl Default for WifiConfig {
             magic: WIFI_CONFIG_MAGIC,
             ssid_len: 0,
             password_len: 0,
-            ssid: [0; 32],
-            password: [0; 64],
-            _padding: [0; 2],
+            ssid: [0; limits::MAX_SSID_LEN],
+            password: [0; limits::MAX_PASSWORD_LEN],
+            preferred_band: Self::BAND_ANY,
+            psk_valid: 0,
+            psk: [0; limits::PSK_LEN],
+            last_error: WifiJoinError::None as u8,
+            locked: 0,
+            security: WifiSecurity::Wpa2Psk as u8,
+            bssid: [0; 6],
+            bssid_valid: 0,
+            hidden: 0,
+            _padding: [0; 1],
         }
     }
 }
 
+impl core::fmt::Debug for WifiConfig {
+    /// Formats the configuration without revealing secrets
+    ///
+    /// The SSID is shown as text when it is valid UTF-8. The password is
+    /// rendered as `<redacted N bytes>` and the cached PSK is only reported
+    /// as present or absent.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut s = f.debug_struct("WifiConfig");
+        match core::str::from_utf8(self.get_ssid()) {
+            Ok(ssid) => s.field("ssid", &ssid),
+            Err(_) => s.field("ssid", &self.get_ssid()),
+        };
+        s.field("password", &Redacted(self.get_password().len()))
+            .field("preferred_band", &self.preferred_band)
+            .field("psk_valid", &(self.psk_valid != 0))
+            .field("last_error", &self.get_last_error())
+            .field("security", &self.get_security())
+            .field("bssid", &self.get_bssid())
+            .field("hidden", &self.is_hidden())
+            .field("locked", &self.is_locked())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for WifiConfig {
+    /// Formats the configuration without revealing secrets
+    ///
+    /// Mirrors the `Debug` output: the password is only shown as its length.
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "WifiConfig {{ ssid: {=[u8]:a}, password: <redacted {=usize} bytes>, preferred_band: {=u8}, psk_valid: {=bool}, last_error: {}, security: {}, bssid: {}, hidden: {=bool}, locked: {=bool}, .. }}",
+            self.get_ssid(),
+            self.get_password().len(),
+            self.preferred_band,
+            self.psk_valid != 0,
+            self.get_last_error(),
+            self.get_security(),
+            self.get_bssid(),
+            self.is_hidden(),
+            self.is_locked(),
+        );
+    }
+}
+
+/// Placeholder printed instead of a secret of the given length
+struct Redacted(usize);
+
+impl core::fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "<redacted {} bytes>", self.0)
+    }
+}
+
+/// Frequency band constants for the band-steering preference
+impl WifiConfig {
+    /// No band preference
+    pub const BAND_ANY: u8 = 0;
+    /// Prefer the 2.4 GHz band
+    pub const BAND_2_4GHZ: u8 = 1;
+    /// Prefer the 5 GHz band
+    pub const BAND_5GHZ: u8 = 2;
+    /// Prefer the 6 GHz band
+    pub const BAND_6GHZ: u8 = 3;
+}
+
 impl WifiConfig {
     /// Creates a new Wi-Fi configuration with the provided SSID and password
     ///
@@ -76,6 +173,241 @@ impl WifiConfig {
         Ok(wf)
     }
 
+    /// Creates an all-zero Wi-Fi configuration with only the magic number set
+    ///
+    /// Equivalent to `default()` for the current layout, but guaranteed to
+    /// stay all-zero should non-zero defaults be introduced.
+    #[must_use]
+    pub fn new_zeroed_with_magic() -> Self {
+        Self {
+            magic: WIFI_CONFIG_MAGIC,
+            ..Zeroable::zeroed()
+        }
+    }
+
+    /// Creates a new Wi-Fi configuration from a Wi-Fi QR code payload
+    ///
+    /// Parses the de-facto standard `WIFI:T:WPA;S:ssid;P:password;H:false;;`
+    /// format. Field values may contain `\`-escaped special characters
+    /// (`\;`, `\,`, `\:`, `\\`, `\"`). Unknown fields are ignored.
+    ///
+    /// # Parameters
+    /// - `payload`: QR code text starting with `WIFI:`
+    ///
+    /// # Returns
+    /// - `Ok(WifiConfig)` if the payload was parsed successfully
+    /// - `Err(Error)` if the payload is malformed or the credentials are too long
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidCredentialFormat` if the payload lacks the `WIFI:` prefix
+    /// or an SSID, contains a field without a `key:` part, has a dangling escape, or
+    /// has an unrecognized `T` (security) or `H` (hidden) value.
+    /// Returns `Error::CredentialLengthExceeded` if the SSID exceeds 32 bytes or the
+    /// password exceeds 64 bytes.
+    ///
+    /// # Note
+    /// The `T` field is stored as the security type: `nopass` maps to
+    /// `WifiSecurity::Open`, `WEP` to `Wep`, `WPA` to `Wpa2Psk` and `SAE` to
+    /// `Wpa3Sae`. Without a `T` value the default `Wpa2Psk` is kept. The
+    /// `H` field sets the hidden flag.
+    ///
+    /// # Examples
+    /// ```
+    /// use renik::WifiConfig;
+    ///
+    /// let config = WifiConfig::from_wifi_qr("WIFI:T:WPA;S:MyNetwork;P:pass\\;word;;")?;
+    /// assert_eq!(config.get_ssid(), b"MyNetwork");
+    /// assert_eq!(config.get_password(), b"pass;word");
+    /// # Ok::<(), renik::Error>(())
+    /// ```
+    pub fn from_wifi_qr(payload: &str) -> Result<Self, Error> {
+        let mut rest = payload
+            .strip_prefix("WIFI:")
+            .ok_or(Error::InvalidCredentialFormat)?
+            .as_bytes();
+
+        let mut ssid = [0; 32];
+        let mut ssid_len = None;
+        let mut password = [0; 64];
+        let mut password_len = 0;
+        let mut security = WifiSecurity::default();
+        let mut hidden = false;
+
+        // Fields are `;`-terminated; an empty field marks the `;;` end of payload
+        while !rest.is_empty() {
+            let (field, tail) = split_qr_field(rest)?;
+            if field.is_empty() {
+                break;
+            }
+
+            let separator = field
+                .iter()
+                .position(|&b| b == b':')
+                .ok_or(Error::InvalidCredentialFormat)?;
+            let (key, value) = (&field[..separator], &field[separator + 1..]);
+
+            match key {
+                b"S" => ssid_len = Some(unescape_qr_value(value, &mut ssid)?),
+                b"P" => password_len = unescape_qr_value(value, &mut password)?,
+                b"T" => {
+                    security = match value {
+                        b"" | b"WPA" => WifiSecurity::Wpa2Psk,
+                        b"nopass" => WifiSecurity::Open,
+                        b"WEP" => WifiSecurity::Wep,
+                        b"SAE" => WifiSecurity::Wpa3Sae,
+                        _ => return Err(Error::InvalidCredentialFormat),
+                    };
+                }
+                b"H" => {
+                    hidden = match value {
+                        b"" | b"false" => false,
+                        b"true" => true,
+                        _ => return Err(Error::InvalidCredentialFormat),
+                    };
+                }
+                _ => {}
+            }
+
+            rest = tail;
+        }
+
+        let ssid_len = ssid_len.ok_or(Error::InvalidCredentialFormat)?;
+        let mut config = Self::new(&ssid[..ssid_len], &password[..password_len])?;
+        config.set_security(security)?;
+        config.set_hidden(hidden)?;
+        Ok(config)
+    }
+
+    /// Reads a Wi-Fi configuration from a byte slice
+    ///
+    /// Intended for loading a configuration back from flash. The slice must
+    /// hold exactly one structure but does not need to be aligned.
+    ///
+    /// # Parameters
+    /// - `bytes`: Serialized configuration
+    ///
+    /// # Returns
+    /// - `Ok(WifiConfig)` if the bytes hold a valid configuration
+    /// - `Err(Error)` if the slice has the wrong length or the data is invalid
+    ///
+    /// # Errors
+    /// Returns `Error::CorruptData` if the slice length is not exactly
+    /// `size_of::<WifiConfig>()`, the magic number does not match, or a
+    /// stored length exceeds its buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use renik::WifiConfig;
+    ///
+    /// let config = WifiConfig::new(b"MyNetwork", b"password123")?;
+    /// let restored = WifiConfig::from_bytes(bytemuck::bytes_of(&config))?;
+    /// assert_eq!(restored.get_ssid(), b"MyNetwork");
+    /// # Ok::<(), renik::Error>(())
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let config: Self =
+            bytemuck::try_pod_read_unaligned(bytes).map_err(|_| Error::CorruptData)?;
+        config.check_stored()?;
+        Ok(config)
+    }
+
+    /// Reads a Wi-Fi configuration from a byte slice, ignoring trailing bytes
+    ///
+    /// Unlike `from_bytes`, longer slices are accepted. Intended for flash
+    /// images written by older firmware whose record was longer than the
+    /// current structure. Only the first `size_of::<WifiConfig>()` bytes are
+    /// read; the slice does not need to be aligned.
+    ///
+    /// # Parameters
+    /// - `bytes`: Serialized configuration, at least as long as the structure
+    ///
+    /// # Returns
+    /// - `Ok(WifiConfig)` if the bytes hold a valid configuration
+    /// - `Err(Error)` if the slice is too short or the data is invalid
+    ///
+    /// # Errors
+    /// Returns `Error::CorruptData` if the slice is shorter than the structure,
+    /// the magic number does not match, or a stored length exceeds its buffer.
+    pub fn from_bytes_relaxed(bytes: &[u8]) -> Result<Self, Error> {
+        let bytes = bytes
+            .get(..core::mem::size_of::<Self>())
+            .ok_or(Error::CorruptData)?;
+        let config: Self = bytemuck::pod_read_unaligned(bytes);
+        config.check_stored()?;
+        Ok(config)
+    }
+
+    /// Borrows a Wi-Fi configuration from a byte slice without copying it
+    ///
+    /// Intended for memory-mapped flash, where copying the structure out on
+    /// every read is wasteful. The slice must hold exactly one structure and
+    /// be 4-byte aligned.
+    ///
+    /// # Parameters
+    /// - `bytes`: Serialized configuration
+    ///
+    /// # Returns
+    /// - `Ok(WifiConfigRef)` borrowing `bytes`
+    /// - `Err(Error)` if the slice cannot be reinterpreted or the data is invalid
+    ///
+    /// # Errors
+    /// Returns `Error::CorruptData` if the slice length is not exactly
+    /// `size_of::<WifiConfig>()`, the slice is misaligned, the magic number
+    /// does not match, or a stored length exceeds its buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use renik::WifiConfig;
+    ///
+    /// let config = WifiConfig::new(b"MyNetwork", b"password123")?;
+    /// let view = WifiConfig::ref_from_bytes(bytemuck::bytes_of(&config))?;
+    /// assert_eq!(view.get_ssid(), b"MyNetwork");
+    /// # Ok::<(), renik::Error>(())
+    /// ```
+    pub fn ref_from_bytes(bytes: &[u8]) -> Result<WifiConfigRef<'_>, Error> {
+        let config: &Self = bytemuck::try_from_bytes(bytes).map_err(|_| Error::CorruptData)?;
+        config.check_stored()?;
+        Ok(WifiConfigRef(config))
+    }
+
+    /// Checks that no data is stored past the SSID and password lengths
+    ///
+    /// The setters zero every byte past the effective length, so a non-zero
+    /// byte there points to a length field that is too small, e.g. after a
+    /// partial flash write or a manual byte patch. A length that is too large
+    /// is also reported.
+    ///
+    /// # Returns
+    /// - `true` if both length fields agree with their buffers
+    /// - `false` otherwise
+    ///
+    /// # Note
+    /// This is an advisory heuristic: a length that is too large but still
+    /// within its buffer, or trailing zero bytes that belong to the value,
+    /// cannot be detected.
+    #[must_use]
+    pub fn lengths_are_consistent(&self) -> bool {
+        let ssid_len = usize::from(self.ssid_len);
+        let password_len = usize::from(self.password_len);
+
+        ssid_len <= self.ssid.len()
+            && password_len <= self.password.len()
+            && util::tail_is_zero(&self.ssid, ssid_len)
+            && util::tail_is_zero(&self.password, password_len)
+    }
+
+    /// Checks the magic number and length fields of data read from storage
+    fn check_stored(&self) -> Result<(), Error> {
+        if !self.is_valid()
+            || self.ssid_len as usize > self.ssid.len()
+            || self.password_len as usize > self.password.len()
+        {
+            return Err(Error::CorruptData);
+        }
+
+        Ok(())
+    }
+
     /// Validates the Wi-Fi configuration structure
     ///
     /// # Returns
@@ -90,6 +422,20 @@ impl WifiConfig {
         self.magic == WIFI_CONFIG_MAGIC
     }
 
+    /// Checks whether network credentials have been set
+    ///
+    /// # Returns
+    /// - `true` if a non-empty SSID is stored
+    /// - `false` otherwise
+    ///
+    /// # Note
+    /// Unlike `is_valid`, this distinguishes a populated configuration from
+    /// a freshly defaulted one.
+    #[must_use]
+    pub fn is_configured(&self) -> bool {
+        self.ssid_len != 0
+    }
+
     /// Sets the Wi-Fi network credentials
     ///
     /// # Parameters
@@ -101,16 +447,21 @@ impl WifiConfig {
     /// - `Err(Error)` if the SSID or password length exceeded the maximum allowed
     ///
     /// # Errors
-    /// Returns `Error::CredentialLengthExceeded` if either the SSID exceeds 32 bytes
+    /// Returns `Error::ConfigLocked` if the configuration has been locked, or
+    /// `Error::CredentialLengthExceeded` if either the SSID exceeds 32 bytes
     /// or the password exceeds 64 bytes.
     ///
     /// # Behavior
     /// - Clears existing credential buffers before setting new values
     /// - Updates length fields to reflect actual credential sizes
     /// - Pads unused buffer space with zeros
+    /// - Invalidates the cached PSK
     #[allow(clippy::cast_possible_truncation)]
     pub fn set_credentials(&mut self, ssid: &[u8], password: &[u8]) -> Result<(), Error> {
-        if ssid.len() > 32 || password.len() > 64 {
+        if self.is_locked() {
+            return Err(Error::ConfigLocked);
+        }
+        if ssid.len() > limits::MAX_SSID_LEN || password.len() > limits::MAX_PASSWORD_LEN {
             return Err(Error::CredentialLengthExceeded);
         }
 
@@ -125,17 +476,202 @@ impl WifiConfig {
         // Copy new credentials into buffers
         self.ssid[..ssid.len()].copy_from_slice(ssid);
         self.password[..password.len()].copy_from_slice(password);
+        debug_assert!(util::tail_is_zero(&self.ssid, ssid.len()));
+        debug_assert!(util::tail_is_zero(&self.password, password.len()));
 
+        self.invalidate_psk();
         Ok(())
     }
 
+    /// Sets the SSID, truncating it to 32 bytes if necessary
+    ///
+    /// The stored password is left unchanged. The cached PSK is invalidated,
+    /// as it is salted with the SSID.
+    ///
+    /// # Parameters
+    /// - `ssid`: Network name as byte slice (any length)
+    ///
+    /// # Returns
+    /// - `Ok(true)` if the SSID was longer than 32 bytes and got truncated
+    /// - `Ok(false)` if the whole SSID was stored
+    ///
+    /// # Errors
+    /// Returns `Error::ConfigLocked` if the configuration has been locked.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn set_ssid_truncating(&mut self, ssid: &[u8]) -> Result<bool, Error> {
+        if self.is_locked() {
+            return Err(Error::ConfigLocked);
+        }
+        let len = ssid.len().min(self.ssid.len());
+
+        self.ssid_len = len as u8;
+        self.ssid.fill(0);
+        self.ssid[..len].copy_from_slice(&ssid[..len]);
+        debug_assert!(util::tail_is_zero(&self.ssid, len));
+        self.invalidate_psk();
+        Ok(len < ssid.len())
+    }
+
+    /// Locks the credentials against further modification
+    ///
+    /// Once locked, `set_credentials`, `set_ssid_truncating`, `sanitize_ssid`,
+    /// `trim_ssid_trailing_spaces`, `set_security`, `set_bssid`, `clear_bssid`
+    /// and `set_hidden` return `Error::ConfigLocked`. The cached PSK,
+    /// preferred band and last join error remain writable. The lock flag
+    /// persists when the structure is serialized.
+    pub fn lock(&mut self) {
+        self.locked = 1;
+    }
+
+    /// Returns whether the credentials are locked
+    ///
+    /// # Returns
+    /// - `true` if `lock` has been called
+    /// - `false` otherwise
+    #[must_use]
+    pub fn is_locked(&self) -> bool {
+        self.locked != 0
+    }
+
     /// Returns the stored SSID as a byte slice
     ///
     /// # Returns
     /// A slice containing only the valid SSID bytes (length determined by `ssid_len`)
     #[must_use]
     pub fn get_ssid(&self) -> &[u8] {
-        &self.ssid[..self.ssid_len as usize]
+        util::prefix(&self.ssid, self.ssid_len)
+    }
+
+    /// Replaces control characters in the stored SSID with `?`
+    ///
+    /// Bytes below 0x20 and the DEL byte (0x7F) are replaced in place; the
+    /// SSID length is preserved. Bytes of 0x80 and above are left untouched
+    /// so UTF-8 encoded SSIDs remain intact.
+    ///
+    /// # Errors
+    /// Returns `Error::ConfigLocked` if the configuration has been locked.
+    pub fn sanitize_ssid(&mut self) -> Result<(), Error> {
+        if self.is_locked() {
+            return Err(Error::ConfigLocked);
+        }
+        let len = self.get_ssid().len();
+        for b in &mut self.ssid[..len] {
+            *b = sanitize_byte(*b);
+        }
+        Ok(())
+    }
+
+    /// Removes trailing spaces from the stored SSID
+    ///
+    /// Only trailing 0x20 bytes are removed; leading spaces can be part of a
+    /// real SSID and are kept. This is opt-in: nothing trims SSIDs
+    /// implicitly. If the SSID changes, the cached PSK is invalidated since
+    /// it was derived from the old SSID.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if any bytes were removed
+    /// - `Ok(false)` if the SSID had no trailing spaces
+    ///
+    /// # Errors
+    /// Returns `Error::ConfigLocked` if the configuration has been locked.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn trim_ssid_trailing_spaces(&mut self) -> Result<bool, Error> {
+        if self.is_locked() {
+            return Err(Error::ConfigLocked);
+        }
+        let ssid = self.get_ssid();
+        let trimmed = ssid.iter().rposition(|&b| b != b' ').map_or(0, |i| i + 1);
+        if trimmed == ssid.len() {
+            return Ok(false);
+        }
+
+        self.ssid[trimmed..].fill(0);
+        // Safe cast: trimmed is below the previous SSID length
+        self.ssid_len = trimmed as u8;
+        self.invalidate_psk();
+        debug_assert!(util::tail_is_zero(&self.ssid, trimmed));
+        Ok(true)
+    }
+
+    /// Writes a display-safe copy of the SSID into a caller buffer
+    ///
+    /// Applies the same replacement as `sanitize_ssid` without modifying the
+    /// stored SSID.
+    ///
+    /// # Parameters
+    /// - `out`: Destination buffer, must hold at least the SSID length
+    ///
+    /// # Returns
+    /// - `Ok(usize)` with the number of bytes written
+    /// - `Err(Error)` if the buffer is too small
+    ///
+    /// # Errors
+    /// Returns `Error::BufferTooSmall` if `out` is shorter than the SSID.
+    pub fn sanitized_ssid(&self, out: &mut [u8]) -> Result<usize, Error> {
+        let ssid = self.get_ssid();
+        let out = out.get_mut(..ssid.len()).ok_or(Error::BufferTooSmall)?;
+
+        for (dst, &src) in out.iter_mut().zip(ssid) {
+            *dst = sanitize_byte(src);
+        }
+
+        Ok(ssid.len())
+    }
+
+    /// Writes a masked copy of the SSID into a caller buffer
+    ///
+    /// The first and last byte are kept and everything in between is replaced
+    /// with `*`, e.g. `MyNetwork` becomes `M*******k`. SSIDs of two bytes or
+    /// less are masked entirely. Kept bytes that are not printable ASCII are
+    /// masked too, so the output is always printable ASCII.
+    ///
+    /// # Parameters
+    /// - `out`: Destination buffer, must hold at least the SSID length
+    ///
+    /// # Returns
+    /// - `Ok(usize)` with the number of bytes written
+    /// - `Err(Error)` if the buffer is too small
+    ///
+    /// # Errors
+    /// Returns `Error::BufferTooSmall` if `out` is shorter than the SSID.
+    pub fn obfuscated_ssid(&self, out: &mut [u8]) -> Result<usize, Error> {
+        let ssid = self.get_ssid();
+        let out = out.get_mut(..ssid.len()).ok_or(Error::BufferTooSmall)?;
+
+        let len = ssid.len();
+        out.fill(b'*');
+        if len > 2 {
+            out[0] = mask_byte(ssid[0]);
+            out[len - 1] = mask_byte(ssid[len - 1]);
+        }
+
+        Ok(len)
+    }
+
+    /// Writes a fully masked placeholder for the password into a caller buffer
+    ///
+    /// The placeholder is always eight `*` characters, so neither the
+    /// content nor the length of the password is revealed. Nothing is written
+    /// for open networks without a password.
+    ///
+    /// # Parameters
+    /// - `out`: Destination buffer, must hold at least 8 bytes
+    ///
+    /// # Returns
+    /// - `Ok(usize)` with the number of bytes written (8, or 0 without a password)
+    /// - `Err(Error)` if the buffer is too small
+    ///
+    /// # Errors
+    /// Returns `Error::BufferTooSmall` if a password is set and `out` is
+    /// shorter than 8 bytes.
+    pub fn obfuscated_password(&self, out: &mut [u8]) -> Result<usize, Error> {
+        if self.password_len == 0 {
+            return Ok(0);
+        }
+
+        let out = out.get_mut(..8).ok_or(Error::BufferTooSmall)?;
+        out.fill(b'*');
+        Ok(out.len())
     }
 
     /// Returns the stored password as a byte slice
@@ -144,6 +680,411 @@ impl WifiConfig {
     /// A slice containing only the valid password bytes (length determined by `password_len`)
     #[must_use]
     pub fn get_password(&self) -> &[u8] {
-        &self.password[..self.password_len as usize]
+        util::prefix(&self.password, self.password_len)
+    }
+
+    /// Returns how many more bytes the SSID could hold
+    ///
+    /// # Returns
+    /// The SSID capacity (32 bytes) minus the current SSID length
+    #[must_use]
+    pub fn ssid_remaining(&self) -> usize {
+        self.ssid.len() - self.get_ssid().len()
+    }
+
+    /// Returns how many more bytes the password could hold
+    ///
+    /// # Returns
+    /// The password capacity (64 bytes) minus the current password length
+    #[must_use]
+    pub fn password_remaining(&self) -> usize {
+        self.password.len() - self.get_password().len()
+    }
+
+    /// Sets the preferred frequency band
+    ///
+    /// Used to choose among scan results when the same SSID is advertised
+    /// on several bands.
+    ///
+    /// # Parameters
+    /// - `band`: One of the `BAND_*` constants (`BAND_ANY` for no preference)
+    pub fn set_preferred_band(&mut self, band: u8) {
+        self.preferred_band = band;
     }
+
+    /// Returns the preferred frequency band
+    ///
+    /// # Returns
+    /// One of the `BAND_*` constants (`BAND_ANY` when no preference is set)
+    #[must_use]
+    pub fn get_preferred_band(&self) -> u8 {
+        self.preferred_band
+    }
+
+    /// Checks whether the given band is the preferred one
+    ///
+    /// # Returns
+    /// - `true` if a preference is set and it matches `band`
+    /// - `false` if there is no preference or a different band is preferred
+    #[must_use]
+    pub fn prefers_band(&self, band: u8) -> bool {
+        self.preferred_band != Self::BAND_ANY && self.preferred_band == band
+    }
+
+    /// Records why the last join attempt failed
+    ///
+    /// The reason is part of the structure, so it survives reboots when the
+    /// configuration is persisted. Call `clear_last_error` after a
+    /// successful connection.
+    ///
+    /// # Parameters
+    /// - `error`: Failure reason of the last join attempt
+    pub fn set_last_error(&mut self, error: WifiJoinError) {
+        self.last_error = error as u8;
+    }
+
+    /// Returns why the last join attempt failed
+    ///
+    /// # Returns
+    /// The recorded failure reason, `WifiJoinError::None` if the last attempt
+    /// succeeded, none was recorded, or the stored value is unrecognized
+    #[must_use]
+    pub fn get_last_error(&self) -> WifiJoinError {
+        match self.last_error {
+            1 => WifiJoinError::WrongPassword,
+            2 => WifiJoinError::ApNotFound,
+            3 => WifiJoinError::AuthTimeout,
+            4 => WifiJoinError::DhcpFailed,
+            5 => WifiJoinError::AssocRejected,
+            _ => WifiJoinError::None,
+        }
+    }
+
+    /// Clears the recorded join failure, e.g. after a successful connection
+    pub fn clear_last_error(&mut self) {
+        self.set_last_error(WifiJoinError::None);
+    }
+
+    /// Sets the network security type
+    ///
+    /// The radio driver uses it to pick the authentication mode.
+    ///
+    /// # Parameters
+    /// - `security`: Security type of the network
+    ///
+    /// # Errors
+    /// Returns `Error::ConfigLocked` if the configuration has been locked.
+    pub fn set_security(&mut self, security: WifiSecurity) -> Result<(), Error> {
+        if self.is_locked() {
+            return Err(Error::ConfigLocked);
+        }
+        self.security = security as u8;
+        Ok(())
+    }
+
+    /// Returns the network security type
+    ///
+    /// # Returns
+    /// The stored security type, `WifiSecurity::Wpa2Psk` if the stored value
+    /// is unrecognized
+    #[must_use]
+    pub fn get_security(&self) -> WifiSecurity {
+        match self.security {
+            0 => WifiSecurity::Open,
+            1 => WifiSecurity::Wep,
+            3 => WifiSecurity::Wpa3Sae,
+            4 => WifiSecurity::WpaWpa2Mixed,
+            _ => WifiSecurity::Wpa2Psk,
+        }
+    }
+
+    /// Pins the configuration to a specific access point
+    ///
+    /// In dense deployments with several access points for the same SSID,
+    /// joining by BSSID makes reconnection deterministic.
+    ///
+    /// # Parameters
+    /// - `bssid`: MAC address of the access point
+    ///
+    /// # Errors
+    /// Returns `Error::ConfigLocked` if the configuration has been locked.
+    pub fn set_bssid(&mut self, bssid: &[u8; 6]) -> Result<(), Error> {
+        if self.is_locked() {
+            return Err(Error::ConfigLocked);
+        }
+        self.bssid = *bssid;
+        self.bssid_valid = 1;
+        Ok(())
+    }
+
+    /// Removes the access point pin
+    ///
+    /// The stored BSSID is cleared, so any access point with the SSID may be
+    /// joined again.
+    ///
+    /// # Errors
+    /// Returns `Error::ConfigLocked` if the configuration has been locked.
+    pub fn clear_bssid(&mut self) -> Result<(), Error> {
+        if self.is_locked() {
+            return Err(Error::ConfigLocked);
+        }
+        self.bssid = [0; 6];
+        self.bssid_valid = 0;
+        Ok(())
+    }
+
+    /// Returns the pinned access point
+    ///
+    /// # Returns
+    /// - `Some([u8; 6])` with the BSSID set by `set_bssid`
+    /// - `None` if no access point is pinned
+    #[must_use]
+    pub fn get_bssid(&self) -> Option<[u8; 6]> {
+        (self.bssid_valid != 0).then_some(self.bssid)
+    }
+
+    /// Marks the network as hidden
+    ///
+    /// Hidden networks do not broadcast their SSID, so the driver has to
+    /// probe for them actively instead of relying on a passive scan.
+    ///
+    /// # Parameters
+    /// - `hidden`: Whether the network hides its SSID
+    ///
+    /// # Errors
+    /// Returns `Error::ConfigLocked` if the configuration has been locked.
+    pub fn set_hidden(&mut self, hidden: bool) -> Result<(), Error> {
+        if self.is_locked() {
+            return Err(Error::ConfigLocked);
+        }
+        self.hidden = u8::from(hidden);
+        Ok(())
+    }
+
+    /// Returns whether the network is hidden
+    ///
+    /// # Returns
+    /// - `true` if the network does not broadcast its SSID
+    /// - `false` otherwise (the default)
+    #[must_use]
+    pub fn is_hidden(&self) -> bool {
+        self.hidden != 0
+    }
+
+    /// Caches a pre-shared key derived from the current credentials
+    ///
+    /// Deriving the PSK from the passphrase takes 4096 PBKDF2 iterations, so
+    /// caching it avoids the cost on every reconnect. The cache is invalidated
+    /// whenever the SSID or password changes.
+    ///
+    /// # Parameters
+    /// - `psk`: 256-bit PSK for the stored SSID and password
+    pub fn set_psk(&mut self, psk: &[u8; 32]) {
+        self.psk.copy_from_slice(psk);
+        self.psk_valid = 1;
+    }
+
+    /// Returns the cached pre-shared key
+    ///
+    /// # Returns
+    /// - `Some(&[u8; 32])` if a PSK is cached for the current credentials
+    /// - `None` if no PSK is cached or it was invalidated
+    #[must_use]
+    pub fn get_psk(&self) -> Option<&[u8; 32]> {
+        (self.psk_valid != 0).then_some(&self.psk)
+    }
+
+    /// Discards the cached pre-shared key
+    ///
+    /// The key buffer is cleared so no stale key material remains.
+    pub fn invalidate_psk(&mut self) {
+        self.psk.fill(0);
+        self.psk_valid = 0;
+    }
+
+    /// Returns a copy of the configuration without the cached pre-shared key
+    ///
+    /// All other fields are copied unchanged. Intended for exporting backups,
+    /// since the key can be derived again from the SSID and password.
+    ///
+    /// # Returns
+    /// A copy with the key buffer zeroed and the key marked invalid
+    #[must_use]
+    pub fn clone_without_psk(&self) -> WifiConfig {
+        let mut config = *self;
+        config.invalidate_psk();
+        config
+    }
+
+    /// Derives the WPA/WPA2 pre-shared key and caches it
+    ///
+    /// Computes PBKDF2-HMAC-SHA1 over the password with the SSID as salt and
+    /// 4096 iterations, as specified by IEEE 802.11i for passphrases.
+    ///
+    /// # Note
+    /// The password is always treated as a passphrase; a 64-digit hex key is
+    /// not decoded and should be stored with `set_psk` instead.
+    #[cfg(feature = "pbkdf2")]
+    pub fn derive_psk(&mut self) {
+        let mut psk = [0; 32];
+        pbkdf2::pbkdf2_hmac::<sha1::Sha1>(self.get_password(), self.get_ssid(), 4096, &mut psk);
+        self.set_psk(&psk);
+    }
+}
+
+/// Read-only view of a Wi-Fi configuration stored in borrowed bytes
+///
+/// Created by [`WifiConfig::ref_from_bytes`]. Dereferences to [`WifiConfig`],
+/// so all of its getters are available without copying the structure.
+#[derive(Debug, Clone, Copy)]
+pub struct WifiConfigRef<'a>(&'a WifiConfig);
+
+impl core::ops::Deref for WifiConfigRef<'_> {
+    type Target = WifiConfig;
+
+    fn deref(&self) -> &WifiConfig {
+        self.0
+    }
+}
+
+/// Security type of a Wi-Fi network
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum WifiSecurity {
+    /// Open network without authentication
+    Open = 0,
+    /// Legacy WEP encryption
+    Wep = 1,
+    /// WPA2 personal with a pre-shared key
+    #[default]
+    Wpa2Psk = 2,
+    /// WPA3 personal with simultaneous authentication of equals
+    Wpa3Sae = 3,
+    /// Mixed WPA/WPA2 personal mode
+    WpaWpa2Mixed = 4,
+}
+
+/// Reason a Wi-Fi join attempt failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum WifiJoinError {
+    /// No failure recorded
+    #[default]
+    None = 0,
+    /// Authentication failed because of a wrong password
+    WrongPassword = 1,
+    /// No access point with the SSID was found
+    ApNotFound = 2,
+    /// The authentication handshake timed out
+    AuthTimeout = 3,
+    /// Associated, but no IP address was obtained via DHCP
+    DhcpFailed = 4,
+    /// The access point rejected the association
+    AssocRejected = 5,
+}
+
+/// Derives a `SoftAP` hotspot SSID from a hardware identifier
+///
+/// Writes `prefix` followed by the last three bytes of `hardware_id` as six
+/// uppercase hex characters into `out`. Identifiers shorter than three bytes
+/// are treated as if zero-padded at the front.
+///
+/// # Parameters
+/// - `hardware_id`: Hardware identifier, without trailing padding
+/// - `prefix`: SSID prefix, e.g. `b"RENIK-"`
+/// - `out`: Destination buffer
+///
+/// # Returns
+/// - `Ok(usize)` with the number of bytes written
+/// - `Err(Error)` if the SSID would be too long or `out` is too small
+///
+/// # Errors
+/// Returns `Error::BufferTooSmall` if the resulting SSID exceeds 32 bytes or
+/// `out` is shorter than the SSID.
+///
+/// # Examples
+/// ```
+/// use renik::derive_hotspot_ssid;
+///
+/// let mut ssid = [0; 32];
+/// let len = derive_hotspot_ssid(&[0x01, 0xAB, 0xCD, 0xEF], b"RENIK-", &mut ssid).unwrap();
+/// assert_eq!(&ssid[..len], b"RENIK-ABCDEF");
+/// ```
+pub fn derive_hotspot_ssid(
+    hardware_id: &[u8],
+    prefix: &[u8],
+    out: &mut [u8],
+) -> Result<usize, Error> {
+    let len = prefix.len() + 6;
+    if len > limits::MAX_SSID_LEN {
+        return Err(Error::BufferTooSmall);
+    }
+    let out = out.get_mut(..len).ok_or(Error::BufferTooSmall)?;
+
+    let mut suffix = [0; 3];
+    let tail = &hardware_id[hardware_id.len().saturating_sub(3)..];
+    suffix[3 - tail.len()..].copy_from_slice(tail);
+
+    out[..prefix.len()].copy_from_slice(prefix);
+    for (chunk, b) in out[prefix.len()..].chunks_exact_mut(2).zip(suffix) {
+        chunk.copy_from_slice(&util::hex_byte(b));
+    }
+
+    Ok(len)
+}
+
+/// Maps control characters (below 0x20 and 0x7F) to `?`
+fn sanitize_byte(b: u8) -> u8 {
+    if b < 0x20 || b == 0x7F { b'?' } else { b }
+}
+
+/// Keeps printable ASCII bytes and maps everything else to `*`
+fn mask_byte(b: u8) -> u8 {
+    if b.is_ascii_graphic() || b == b' ' {
+        b
+    } else {
+        b'*'
+    }
+}
+
+/// Splits the next `;`-terminated field off a Wi-Fi QR payload
+///
+/// Returns the raw (still escaped) field and the remainder after the terminator.
+/// A missing terminator on the final field is tolerated.
+fn split_qr_field(input: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    let mut i = 0;
+    while i < input.len() {
+        match input[i] {
+            b'\\' if i + 1 >= input.len() => return Err(Error::InvalidCredentialFormat),
+            b'\\' => i += 2,
+            b';' => return Ok((&input[..i], &input[i + 1..])),
+            _ => i += 1,
+        }
+    }
+
+    Ok((input, &[]))
+}
+
+/// Removes QR payload escapes from `value`, writing the result into `out`
+///
+/// Returns the unescaped length, or `Error::CredentialLengthExceeded` if it
+/// does not fit into `out`.
+fn unescape_qr_value(value: &[u8], out: &mut [u8]) -> Result<usize, Error> {
+    let mut len = 0;
+    let mut escaped = false;
+
+    for &b in value {
+        if b == b'\\' && !escaped {
+            escaped = true;
+            continue;
+        }
+        escaped = false;
+
+        *out.get_mut(len).ok_or(Error::CredentialLengthExceeded)? = b;
+        len += 1;
+    }
+
+    Ok(len)
 }
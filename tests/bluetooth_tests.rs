@@ -1,6 +1,8 @@
 use renik::{
-    BluetoothConnectionParams, BluetoothConnectionPhase, BluetoothConnectionState,
-    BluetoothDeviceInfo, BluetoothDeviceList, BluetoothSecurityInfo, ConnHandle, Error,
+    AuthRequirements, BluetoothConnectionParams, BluetoothConnectionPhase,
+    BluetoothConnectionState, BluetoothDeviceInfo, BluetoothDeviceList, BluetoothSecurityInfo,
+    Codec, ConnHandle, ConnectionRole, ConnectionSummary, Error, PeripheralPreferredParams, Rssi,
+    Severity, TransitionError, all_valid_transitions, is_valid_unicast_mac, limits,
 };
 
 #[test]
@@ -192,8 +194,8 @@ fn test_conn_handle() {
     let default_handle = ConnHandle::default();
     assert_eq!(default_handle.raw(), 0x0000);
 
-    // Test From trait implementations
-    let handle_from_u16: ConnHandle = 0x0042.into();
+    // Test conversion trait implementations
+    let handle_from_u16 = ConnHandle::try_from(0x0042).unwrap();
     assert_eq!(handle_from_u16.raw(), 0x0042);
 
     let u16_from_handle: u16 = handle_from_u16.into();
@@ -218,6 +220,7 @@ fn test_bluetooth_connection_phase() {
     assert!(!BluetoothConnectionPhase::Connecting.is_connected());
     assert!(BluetoothConnectionPhase::Connected.is_connected());
     assert!(BluetoothConnectionPhase::Authenticating.is_connected());
+    assert!(BluetoothConnectionPhase::Bonding.is_connected());
     assert!(BluetoothConnectionPhase::Ready.is_connected());
 
     // Test is_secure
@@ -476,6 +479,7 @@ fn test_bluetooth_fsm_emergency_transitions() {
         BluetoothConnectionPhase::Connecting,
         BluetoothConnectionPhase::Connected,
         BluetoothConnectionPhase::Authenticating,
+        BluetoothConnectionPhase::Bonding,
         BluetoothConnectionPhase::SettingUpEncryption,
         BluetoothConnectionPhase::FullyConnected,
         BluetoothConnectionPhase::ServiceDiscovery,
@@ -682,7 +686,7 @@ fn test_conn_handle_edge_cases() {
     for i in 0..=0x0EFF {
         let handle = ConnHandle::new(i);
         let converted: u16 = handle.into();
-        let back_converted = ConnHandle::from(converted);
+        let back_converted = ConnHandle::try_from(converted).unwrap();
         assert_eq!(handle, back_converted);
         assert_eq!(converted, i);
     }
@@ -733,3 +737,1813 @@ fn test_bluetooth_connection_state_comprehensive() {
     connection_state.set_connection_handle(None);
     assert_eq!(connection_state.get_connection_handle(), None);
 }
+
+#[test]
+fn test_bluetooth_device_list_collect_macs() {
+    let mut device_list = BluetoothDeviceList::default();
+    let mac_addr1 = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mac_addr2 = [0x98, 0x76, 0x54, 0x32, 0x10, 0xFE];
+    device_list
+        .add_device(BluetoothDeviceInfo::new(&mac_addr1, b"Device 1").unwrap())
+        .unwrap();
+    device_list
+        .add_device(BluetoothDeviceInfo::new(&mac_addr2, b"Device 2").unwrap())
+        .unwrap();
+
+    let mut macs = [[0u8; 6]; 10];
+    assert_eq!(device_list.collect_macs(&mut macs).unwrap(), 2);
+    assert_eq!(macs[0], mac_addr1);
+    assert_eq!(macs[1], mac_addr2);
+    assert_eq!(macs[2], [0; 6]); // Untouched
+
+    // Buffer smaller than the device count
+    let mut small = [[0u8; 6]; 1];
+    assert!(matches!(
+        device_list.collect_macs(&mut small),
+        Err(Error::BufferTooSmall)
+    ));
+}
+
+#[test]
+fn test_bluetooth_device_info_pairing_key_eq() {
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Test Device").unwrap();
+    device.set_pairing_key(b"1234").unwrap();
+
+    assert!(device.pairing_key_eq(b"1234"));
+    assert!(!device.pairing_key_eq(b"1235"));
+    assert!(!device.pairing_key_eq(b"123")); // Prefix only
+    assert!(!device.pairing_key_eq(b"1234\0")); // Stored key plus trailing zero byte
+    assert!(!device.pairing_key_eq(b""));
+
+    // Empty stored key only matches an empty candidate
+    device.set_pairing_key(b"").unwrap();
+    assert!(device.pairing_key_eq(b""));
+    assert!(!device.pairing_key_eq(b"\0"));
+}
+
+#[test]
+fn test_bluetooth_device_list_enumerate() {
+    let mut device_list = BluetoothDeviceList::default();
+    assert_eq!(device_list.enumerate().count(), 0);
+
+    for (i, name) in [b"Device 0", b"Device 1", b"Device 2"].iter().enumerate() {
+        let mac_addr = [0x10, 0x20, 0x30, 0x40, 0x50, i as u8 + 1];
+        device_list
+            .add_device(BluetoothDeviceInfo::new(&mac_addr, *name).unwrap())
+            .unwrap();
+    }
+
+    // Stops at the device count rather than iterating all slots
+    assert_eq!(device_list.enumerate().count(), 3);
+
+    let (index, device) = device_list
+        .enumerate()
+        .find(|(_, device)| device.get_device_name() == b"Device 1")
+        .unwrap();
+    assert_eq!(index, 1);
+    assert_eq!(device.get_mac_address()[5], 2);
+
+    device_list.remove_device(index).unwrap();
+    let names: Vec<&[u8]> = device_list
+        .enumerate()
+        .map(|(_, device)| device.get_device_name())
+        .collect();
+    assert_eq!(names, [&b"Device 0"[..], &b"Device 2"[..]]);
+}
+
+#[test]
+fn test_bluetooth_device_info_fingerprint() {
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let device = BluetoothDeviceInfo::new(&mac_addr, b"Test Device").unwrap();
+
+    // Stable for the same MAC and salt, independent of other fields
+    let mut renamed = device;
+    renamed.set_device_name(b"Renamed").unwrap();
+    assert_eq!(device.fingerprint(b"salt"), renamed.fingerprint(b"salt"));
+
+    // Different salts and different MACs produce different fingerprints
+    assert_ne!(device.fingerprint(b"salt"), device.fingerprint(b"other"));
+    let other = BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBD], b"").unwrap();
+    assert_ne!(device.fingerprint(b"salt"), other.fingerprint(b"salt"));
+
+    // Known FNV-1a 64 value over the MAC bytes followed by the salt
+    assert_eq!(
+        device.fingerprint(b"salt"),
+        [0x90, 0xD4, 0xD4, 0xC9, 0x6A, 0xF8, 0x90, 0x4B]
+    );
+}
+
+#[test]
+fn test_bluetooth_connection_state_summary() {
+    let mut connection_state = BluetoothConnectionState::default();
+    assert_eq!(
+        connection_state.summary(),
+        ConnectionSummary {
+            phase: BluetoothConnectionPhase::Idle,
+            connected: false,
+            authenticated: false,
+            encrypted: false,
+            link_quality: 0,
+            rssi: -127,
+            handle: None,
+        }
+    );
+
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Test Device").unwrap();
+    let mut params = BluetoothConnectionParams::default();
+    params.rssi = -60;
+    device.update_connection_params(&params);
+    let mut security = BluetoothSecurityInfo::default();
+    security.encrypted = 1;
+    device.update_security_info(&security);
+
+    connection_state.set_remote_device(device);
+    connection_state.set_connected(true);
+    connection_state.set_authenticated(true);
+    connection_state.set_link_quality(90);
+    connection_state.set_connection_handle(Some(ConnHandle::new(0x0042)));
+    connection_state.set_connection_phase(BluetoothConnectionPhase::Ready);
+
+    let summary = connection_state.summary();
+    assert_eq!(summary.phase, BluetoothConnectionPhase::Ready);
+    assert!(summary.connected);
+    assert!(summary.authenticated);
+    assert!(summary.encrypted);
+    assert_eq!(summary.link_quality, 90);
+    assert_eq!(summary.rssi, -60);
+    assert_eq!(summary.handle, Some(ConnHandle::new(0x0042)));
+}
+
+#[test]
+fn test_bluetooth_device_info_truncating_setters() {
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Test Device").unwrap();
+
+    assert!(!device.set_device_name_truncating(b"Short"));
+    assert_eq!(device.get_device_name(), b"Short");
+
+    let long_name = [b'N'; 40];
+    assert!(device.set_device_name_truncating(&long_name));
+    assert_eq!(device.get_device_name(), &long_name[..32]);
+
+    assert!(!device.set_device_name_truncating(&long_name[..32]));
+    assert_eq!(device.get_device_name().len(), 32);
+
+    assert!(!device.set_pairing_key_truncating(b"1234"));
+    assert_eq!(device.get_pairing_key(), b"1234");
+
+    let long_key = [b'K'; 70];
+    assert!(device.set_pairing_key_truncating(&long_key));
+    assert_eq!(device.get_pairing_key(), &long_key[..64]);
+
+    // Strict setters still reject over-long input
+    assert!(matches!(
+        device.set_device_name(&long_name),
+        Err(Error::InvalidBluetoothDeviceInfo)
+    ));
+}
+
+#[test]
+fn test_bluetooth_device_info_set_device_name_utf8_safe() {
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Test Device").unwrap();
+
+    assert!(!device.set_device_name_utf8_safe("Küche 🔊").unwrap());
+    assert_eq!(device.get_device_name(), "Küche 🔊".as_bytes());
+
+    // 30 ASCII bytes followed by a 4-byte emoji: cutting at 32 would split it
+    let name = format!("{}🎧", "a".repeat(30));
+    assert!(device.set_device_name_utf8_safe(&name).unwrap());
+    assert_eq!(device.get_device_name(), "a".repeat(30).as_bytes());
+    assert!(core::str::from_utf8(device.get_device_name()).is_ok());
+
+    // Multi-byte characters filling the buffer exactly are kept whole
+    let name = "é".repeat(16); // 32 bytes
+    assert!(!device.set_device_name_utf8_safe(&name).unwrap());
+    assert_eq!(device.get_device_name(), name.as_bytes());
+
+    let name = "é".repeat(17); // 34 bytes
+    assert!(device.set_device_name_utf8_safe(&name).unwrap());
+    assert_eq!(device.get_device_name(), "é".repeat(16).as_bytes());
+}
+
+#[test]
+fn test_bluetooth_device_list_undo_remove() {
+    let mut device_list = BluetoothDeviceList::default();
+    assert!(device_list.last_removed().is_none());
+    assert!(matches!(device_list.undo_remove(), Err(Error::NotFound)));
+
+    let mac_addr1 = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mac_addr2 = [0x98, 0x76, 0x54, 0x32, 0x10, 0xFE];
+    let mut device1 = BluetoothDeviceInfo::new(&mac_addr1, b"Device 1").unwrap();
+    device1.set_pairing_key(b"secret").unwrap();
+    device_list.add_device(device1).unwrap();
+    device_list
+        .add_device(BluetoothDeviceInfo::new(&mac_addr2, b"Device 2").unwrap())
+        .unwrap();
+
+    device_list.remove_device(0).unwrap();
+    assert_eq!(device_list.len(), 1);
+    assert_eq!(
+        device_list.last_removed().unwrap().get_mac_address(),
+        &mac_addr1
+    );
+
+    // Restored at the end of the list with its pairing data intact
+    device_list.undo_remove().unwrap();
+    assert_eq!(device_list.len(), 2);
+    let restored = device_list.get_device(1).unwrap();
+    assert_eq!(restored.get_mac_address(), &mac_addr1);
+    assert_eq!(restored.get_pairing_key(), b"secret");
+    assert!(device_list.last_removed().is_none());
+
+    // A later removal replaces the kept device
+    device_list.remove_device(0).unwrap();
+    device_list.remove_device(0).unwrap();
+    assert_eq!(
+        device_list.last_removed().unwrap().get_mac_address(),
+        &mac_addr1
+    );
+
+    device_list.clear_last_removed();
+    assert!(device_list.last_removed().is_none());
+    assert!(matches!(device_list.undo_remove(), Err(Error::NotFound)));
+}
+
+#[test]
+fn test_bluetooth_device_list_undo_remove_full() {
+    let mut device_list = BluetoothDeviceList::default();
+    for i in 0..10 {
+        let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, i + 1];
+        device_list
+            .add_device(BluetoothDeviceInfo::new(&mac_addr, b"Device").unwrap())
+            .unwrap();
+    }
+
+    device_list.remove_device(3).unwrap();
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xFF];
+    device_list
+        .add_device(BluetoothDeviceInfo::new(&mac_addr, b"Replacement").unwrap())
+        .unwrap();
+
+    assert!(matches!(
+        device_list.undo_remove(),
+        Err(Error::DeviceListFull)
+    ));
+    assert!(device_list.last_removed().is_some()); // Still kept after failure
+}
+
+#[test]
+fn test_bluetooth_device_list_connection_count_aggregates() {
+    let mut device_list = BluetoothDeviceList::default();
+    assert_eq!(device_list.total_connection_count(), 0);
+    assert!(device_list.most_connected_device().is_none());
+
+    for (i, count) in [3, u32::MAX, 7, u32::MAX].iter().enumerate() {
+        let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, i as u8 + 1];
+        let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Device").unwrap();
+        device.set_connection_count(*count);
+        device_list.add_device(device).unwrap();
+    }
+
+    // Widened sum does not overflow
+    assert_eq!(
+        device_list.total_connection_count(),
+        10 + 2 * u64::from(u32::MAX)
+    );
+
+    // First device with the highest count wins a tie
+    let most_connected = device_list.most_connected_device().unwrap();
+    assert_eq!(most_connected.get_mac_address()[5], 2);
+}
+
+#[test]
+fn test_bluetooth_fsm_checked_transitions() {
+    let mut connection_state = BluetoothConnectionState::default();
+
+    connection_state
+        .advance_to_phase_checked(BluetoothConnectionPhase::Connecting)
+        .unwrap();
+
+    let err = connection_state
+        .advance_to_phase_checked(BluetoothConnectionPhase::Ready)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransitionError::IllegalTransition {
+            from: BluetoothConnectionPhase::Connecting,
+            to: BluetoothConnectionPhase::Ready,
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "Illegal connection phase transition from Connecting to Ready"
+    );
+
+    // Rejected transition leaves the phase unchanged
+    assert_eq!(
+        connection_state.get_connection_phase(),
+        BluetoothConnectionPhase::Connecting
+    );
+
+    // Reset to Idle is always allowed
+    assert!(
+        connection_state
+            .advance_to_phase_checked(BluetoothConnectionPhase::Idle)
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_bluetooth_device_info_meets_security_policy() {
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Test Device").unwrap();
+
+    // Defaults: security level 1, no MITM, not encrypted
+    assert!(device.meets_security_policy(1, false, false));
+    assert!(!device.meets_security_policy(2, false, false));
+    assert!(!device.meets_security_policy(1, true, false));
+    assert!(!device.meets_security_policy(1, false, true));
+
+    let mut security = BluetoothSecurityInfo::default();
+    security.security_level = 3;
+    security.mitm_required = 1;
+    security.encrypted = 1;
+    device.update_security_info(&security);
+
+    // MITM protection was requested but not negotiated
+    assert!(device.meets_security_policy(3, false, true));
+    assert!(!device.meets_security_policy(3, true, true));
+
+    security.auth_requirements = (AuthRequirements::BONDING | AuthRequirements::MITM).bits();
+    device.update_security_info(&security);
+    assert!(device.meets_security_policy(3, true, true));
+    assert!(device.meets_security_policy(2, true, false));
+    assert!(!device.meets_security_policy(4, true, true));
+
+    security.encrypted = 0;
+    device.update_security_info(&security);
+    assert!(device.meets_security_policy(3, true, false));
+    assert!(!device.meets_security_policy(3, true, true));
+}
+
+#[test]
+fn test_bluetooth_device_list_remove_by_mac() {
+    let mut device_list = BluetoothDeviceList::default();
+    let macs = [
+        [0x12, 0x34, 0x56, 0x78, 0x9A, 0x01],
+        [0x12, 0x34, 0x56, 0x78, 0x9A, 0x02],
+        [0x12, 0x34, 0x56, 0x78, 0x9A, 0x03],
+    ];
+    for mac in &macs {
+        let mut device = BluetoothDeviceInfo::new(mac, b"Device").unwrap();
+        device.set_pairing_key(b"key").unwrap();
+        device_list.add_device(device).unwrap();
+    }
+
+    let removed = device_list.remove_by_mac(&macs[1]).unwrap();
+    assert_eq!(removed.get_mac_address(), &macs[1]);
+    assert_eq!(removed.get_pairing_key(), b"key");
+    assert_eq!(device_list.len(), 2);
+    assert_eq!(
+        device_list.get_device(0).unwrap().get_mac_address(),
+        &macs[0]
+    );
+    assert_eq!(
+        device_list.get_device(1).unwrap().get_mac_address(),
+        &macs[2]
+    );
+
+    assert!(matches!(
+        device_list.remove_by_mac(&macs[1]),
+        Err(Error::NotFound)
+    ));
+    assert_eq!(device_list.len(), 2);
+}
+
+#[test]
+fn test_bluetooth_device_list_compact() {
+    let mut device_list = BluetoothDeviceList::default();
+    for i in 0..6u8 {
+        let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, i + 1];
+        let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Device").unwrap();
+        device.set_pairing_key(b"secret").unwrap();
+        if i % 2 == 1 {
+            device.add_flag(BluetoothDeviceInfo::FLAG_TRUSTED);
+        }
+        device_list.add_device(device).unwrap();
+    }
+
+    device_list.compact(BluetoothDeviceInfo::is_trusted);
+
+    assert_eq!(device_list.len(), 3);
+    let macs: Vec<u8> = device_list
+        .enumerate()
+        .map(|(_, device)| device.get_mac_address()[5])
+        .collect();
+    assert_eq!(macs, [2, 4, 6]); // Order preserved
+
+    // Vacated tail slots no longer hold pairing data
+    let list_bytes = bytemuck::bytes_of(&device_list);
+    let device_size = core::mem::size_of::<BluetoothDeviceInfo>();
+    let default_device = BluetoothDeviceInfo::default();
+    for slot in 3..6 {
+        let offset = 4 + slot * device_size;
+        assert_eq!(
+            &list_bytes[offset..offset + device_size],
+            bytemuck::bytes_of(&default_device)
+        );
+    }
+
+    // Compacting with an always-true predicate is a no-op
+    device_list.compact(|_| true);
+    assert_eq!(device_list.len(), 3);
+
+    device_list.compact(|_| false);
+    assert!(device_list.is_empty());
+}
+
+#[test]
+fn test_bluetooth_by_value_accessors() {
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Test Device").unwrap();
+
+    let mut security = BluetoothSecurityInfo::default();
+    security.link_key = [0xA5; 16];
+    device.update_security_info(&security);
+
+    let mac = device.mac_address();
+    let link_key = device.get_security_info().link_key_copy();
+
+    // Copies stay intact after the device changes
+    device.set_mac_address(&[0; 6]);
+    device.update_security_info(&BluetoothSecurityInfo::default());
+    assert_eq!(mac, mac_addr);
+    assert_eq!(link_key, [0xA5; 16]);
+}
+
+#[test]
+fn test_bluetooth_device_info_eui64() {
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let device = BluetoothDeviceInfo::new(&mac_addr, b"Mesh Node").unwrap();
+
+    let eui64 = device.eui64();
+    assert_eq!(eui64, [0x12, 0x34, 0x56, 0xFF, 0xFE, 0x78, 0x9A, 0xBC]);
+    assert_eq!(BluetoothDeviceInfo::from_eui64(eui64), mac_addr);
+}
+
+#[test]
+fn test_bluetooth_device_list_iter_by_type() {
+    let mut device_list = BluetoothDeviceList::default();
+    let classes: [(&[u8], [u8; 3]); 4] = [
+        (b"Headphones", [0x04, 0x10, 0x24]), // Audio
+        (b"Keyboard", [0x40, 0x14, 0x00]),   // Peripheral
+        (b"Speaker", [0x14, 0x10, 0x24]),    // Audio
+        (b"Unknown", [0x00, 0x00, 0x00]),
+    ];
+
+    for (i, (name, class)) in classes.iter().enumerate() {
+        let mac_addr = [0x10, 0x20, 0x30, 0x40, 0x50, i as u8];
+        let mut device = BluetoothDeviceInfo::new(&mac_addr, name).unwrap();
+        device.set_class_of_device(class);
+        device_list.add_device(device).unwrap();
+    }
+
+    let audio: Vec<&[u8]> = device_list
+        .iter_by_type(BluetoothDeviceInfo::DEVICE_TYPE_AUDIO)
+        .map(|device| device.get_device_name())
+        .collect();
+    assert_eq!(audio, vec![&b"Headphones"[..], &b"Speaker"[..]]);
+
+    assert_eq!(
+        device_list
+            .iter_by_type(BluetoothDeviceInfo::DEVICE_TYPE_PERIPHERAL)
+            .count(),
+        1
+    );
+    assert_eq!(
+        device_list
+            .iter_by_type(BluetoothDeviceInfo::DEVICE_TYPE_PHONE)
+            .count(),
+        0
+    );
+
+    // Unused slots are default (unknown type) but must not be yielded
+    assert_eq!(
+        device_list
+            .iter_by_type(BluetoothDeviceInfo::DEVICE_TYPE_UNKNOWN)
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_bluetooth_device_list_find_duplicate() {
+    let mut device_list = BluetoothDeviceList::default();
+    assert!(!device_list.has_duplicates());
+    assert_eq!(device_list.find_duplicate(), None);
+
+    let macs = [
+        [0x10, 0x20, 0x30, 0x40, 0x50, 0x01],
+        [0x10, 0x20, 0x30, 0x40, 0x50, 0x02],
+        [0x10, 0x20, 0x30, 0x40, 0x50, 0x03],
+    ];
+    for mac_addr in &macs {
+        device_list
+            .add_device(BluetoothDeviceInfo::new(mac_addr, b"Device").unwrap())
+            .unwrap();
+    }
+    assert!(!device_list.has_duplicates());
+
+    // add_device does not reject duplicates, as a buggy import might produce
+    device_list
+        .add_device(BluetoothDeviceInfo::new(&macs[2], b"Copy").unwrap())
+        .unwrap();
+    device_list
+        .add_device(BluetoothDeviceInfo::new(&macs[1], b"Copy").unwrap())
+        .unwrap();
+    assert!(device_list.has_duplicates());
+
+    // The earliest device with a later duplicate wins
+    assert_eq!(device_list.find_duplicate(), Some(macs[1]));
+    assert_eq!(device_list.len(), 5);
+}
+
+#[test]
+fn test_bluetooth_rssi_typed() {
+    let mut params = BluetoothConnectionParams::default();
+    assert_eq!(params.rssi_typed(), Rssi::UNKNOWN);
+    assert_eq!(params.rssi_typed().dbm(), None);
+    assert_eq!(Rssi::default(), Rssi::UNKNOWN);
+
+    params.rssi = -60;
+    let rssi = params.rssi_typed();
+    assert!(rssi.is_known());
+    assert_eq!(rssi.dbm(), Some(-60));
+    assert_eq!(i8::from(rssi), -60);
+
+    // Unknown ranks below every measured value, including i8::MIN
+    let mut readings = [
+        Rssi::from(-50),
+        Rssi::UNKNOWN,
+        Rssi::from(i8::MIN),
+        Rssi::from(-90),
+    ];
+    readings.sort();
+    assert_eq!(
+        readings,
+        [
+            Rssi::UNKNOWN,
+            Rssi::from(i8::MIN),
+            Rssi::from(-90),
+            Rssi::from(-50)
+        ]
+    );
+    assert_eq!(readings.iter().max(), Some(&Rssi::from(-50)));
+}
+
+#[test]
+fn test_bluetooth_new_zeroed_with_magic() {
+    let mut device = BluetoothDeviceInfo::new_zeroed_with_magic();
+    let bytes = bytemuck::bytes_of(&device);
+    assert_eq!(&bytes[..4], &0x4254_4C45u32.to_le_bytes());
+    assert!(bytes[4..].iter().all(|&b| b == 0));
+
+    // None of the non-zero defaults are applied
+    assert_eq!(device.get_connection_params().rssi, 0);
+    assert_eq!(device.get_security_info().security_level, 0);
+
+    device.set_mac_address(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]);
+    assert!(device.is_valid());
+
+    let mut device_list = BluetoothDeviceList::new_zeroed_with_magic();
+    let bytes = bytemuck::bytes_of(&device_list);
+    assert_eq!(&bytes[..4], &0x4254_4C53u32.to_le_bytes());
+    assert!(bytes[4..].iter().all(|&b| b == 0));
+    assert!(device_list.is_empty());
+    device_list.add_device(device).unwrap();
+    assert_eq!(device_list.len(), 1);
+
+    let state = BluetoothConnectionState::new_zeroed_with_magic();
+    let bytes = bytemuck::bytes_of(&state);
+    assert_eq!(&bytes[..4], &0x4254_4353u32.to_le_bytes());
+    assert!(bytes[4..].iter().all(|&b| b == 0));
+    assert_eq!(state.get_connection_phase(), BluetoothConnectionPhase::Idle);
+}
+
+#[test]
+fn test_bluetooth_fsm_bonding_phase() {
+    let mut connection_state = BluetoothConnectionState::default();
+    connection_state.set_connection_phase(BluetoothConnectionPhase::Authenticating);
+
+    // Authenticating -> Bonding -> SettingUpEncryption
+    assert!(connection_state.advance_to_phase(BluetoothConnectionPhase::Bonding));
+    assert_eq!(
+        connection_state.get_connection_phase(),
+        BluetoothConnectionPhase::Bonding
+    );
+    assert!(!BluetoothConnectionPhase::Bonding.is_secure());
+    assert!(!connection_state.advance_to_phase(BluetoothConnectionPhase::FullyConnected));
+    assert!(connection_state.advance_to_phase(BluetoothConnectionPhase::SettingUpEncryption));
+
+    // The direct path without bonding stays legal
+    connection_state.set_connection_phase(BluetoothConnectionPhase::Authenticating);
+    assert!(connection_state.advance_to_phase(BluetoothConnectionPhase::SettingUpEncryption));
+
+    // Bonding can fail or be aborted, and is only reachable from Authenticating
+    connection_state.set_connection_phase(BluetoothConnectionPhase::Bonding);
+    assert!(connection_state.advance_to_phase(BluetoothConnectionPhase::Failed));
+    connection_state.set_connection_phase(BluetoothConnectionPhase::Bonding);
+    assert!(connection_state.advance_to_phase(BluetoothConnectionPhase::Disconnecting));
+    connection_state.set_connection_phase(BluetoothConnectionPhase::Connected);
+    assert!(!connection_state.advance_to_phase(BluetoothConnectionPhase::Bonding));
+
+    // Numeric mapping: Bonding takes 5, later phases moved up by one
+    assert_eq!(BluetoothConnectionPhase::Bonding as u8, 5);
+    assert_eq!(BluetoothConnectionPhase::SettingUpEncryption as u8, 6);
+    assert_eq!(BluetoothConnectionPhase::Disconnecting as u8, 13);
+    connection_state.set_connection_phase(BluetoothConnectionPhase::Disconnecting);
+    assert_eq!(
+        connection_state.get_connection_phase(),
+        BluetoothConnectionPhase::Disconnecting
+    );
+}
+
+#[test]
+fn test_bluetooth_auth_requirements_typed() {
+    let mut security = BluetoothSecurityInfo::default();
+    assert_eq!(
+        security.auth_requirements_typed().unwrap(),
+        AuthRequirements::empty()
+    );
+
+    let mut requirements = AuthRequirements::BONDING | AuthRequirements::MITM;
+    requirements |= AuthRequirements::SECURE_CONNECTIONS;
+    security.set_auth_requirements_typed(requirements);
+    assert_eq!(security.auth_requirements, 0x0D);
+
+    let read_back = security.auth_requirements_typed().unwrap();
+    assert!(read_back.contains(AuthRequirements::MITM | AuthRequirements::BONDING));
+    assert!(!read_back.contains(AuthRequirements::KEYPRESS));
+
+    requirements.remove(AuthRequirements::BONDING);
+    assert_eq!(requirements.bits(), 0x0C);
+
+    // Reserved bits (6-7 and the reserved bonding-flags value) are rejected
+    for raw in [0x40, 0x80, 0x02, 0xFF] {
+        assert!(matches!(
+            AuthRequirements::from_bits(raw),
+            Err(Error::InvalidCredentialFormat)
+        ));
+    }
+    assert_eq!(AuthRequirements::try_from(0x3D).unwrap().bits(), 0x3D);
+
+    // A raw byte written directly is validated on read
+    security.auth_requirements = 0xFF;
+    assert!(matches!(
+        security.auth_requirements_typed(),
+        Err(Error::InvalidCredentialFormat)
+    ));
+}
+
+#[test]
+fn test_bluetooth_tail_zeroing_invariants() {
+    // Shrinking values and removals leave no stale bytes past the
+    // effective length; setters also debug-assert this after every write
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mut device = BluetoothDeviceInfo::new(&mac_addr, &[b'N'; 32]).unwrap();
+    device.set_pairing_key(&[b'K'; 64]).unwrap();
+    device.set_device_name(b"Short").unwrap();
+    device.set_pairing_key(b"1234").unwrap();
+
+    let bytes = bytemuck::bytes_of(&device);
+    assert!(!bytes.windows(2).any(|w| w == b"NN"));
+    assert!(!bytes.windows(2).any(|w| w == b"KK"));
+
+    let mut device_list = BluetoothDeviceList::default();
+    for i in 0..6u8 {
+        let mut entry = device;
+        entry.set_mac_address(&[0x10, 0x20, 0x30, 0x40, 0x50, i + 1]);
+        device_list.add_device(entry).unwrap();
+    }
+    device_list.remove_device(0).unwrap();
+    device_list.remove_device(4).unwrap();
+    device_list.compact(|device| device.get_mac_address()[5] % 2 == 0);
+    device_list.clear_last_removed();
+    assert_eq!(device_list.len(), 2);
+}
+
+#[test]
+fn test_bluetooth_device_list_mutations_on_non_default_slots() {
+    // Zeroed and stale unused slots are legal and must not trip any check
+    let device =
+        BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], b"Device").unwrap();
+
+    let mut device_list = BluetoothDeviceList::new_zeroed_with_magic();
+    device_list.add_device(device).unwrap();
+    device_list.remove_device(0).unwrap();
+    device_list.add_device(device).unwrap();
+    device_list.compact(|_| false);
+    device_list.clear_last_removed();
+    assert!(device_list.is_empty());
+
+    // A list read from flash whose tail still holds an old device
+    let mut full = BluetoothDeviceList::default();
+    full.add_device(device).unwrap();
+    full.add_device(device).unwrap();
+    let mut bytes = bytemuck::bytes_of(&full).to_vec();
+    let count_offset = 4 + (limits::MAX_DEVICES + 1) * core::mem::size_of::<BluetoothDeviceInfo>();
+    bytes[count_offset] = 1;
+    let mut stale: BluetoothDeviceList = bytemuck::pod_read_unaligned(&bytes);
+    assert_eq!(stale.len(), 1);
+    stale.remove_device(0).unwrap();
+    assert!(stale.is_empty());
+}
+
+#[test]
+fn test_bluetooth_connection_state_security_accessors() {
+    let mut connection_state = BluetoothConnectionState::default();
+    assert_eq!(connection_state.link_key(), None);
+    assert!(!connection_state.is_link_encrypted());
+    assert_eq!(connection_state.security_level(), 1);
+
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Headset").unwrap();
+    let mut security = BluetoothSecurityInfo::default();
+    security.link_key = [0x42; 16];
+    security.security_level = 4;
+    security.encrypted = 1;
+    device.update_security_info(&security);
+    connection_state.set_remote_device(device);
+
+    // A key that is not marked valid is not handed out
+    assert_eq!(connection_state.link_key(), None);
+    assert!(connection_state.is_link_encrypted());
+    assert_eq!(connection_state.security_level(), 4);
+
+    security.link_key_valid = 1;
+    device.update_security_info(&security);
+    connection_state.set_remote_device(device);
+    assert_eq!(connection_state.link_key(), Some(&[0x42; 16]));
+}
+
+#[test]
+fn test_bluetooth_connection_params_role() {
+    let mut params = BluetoothConnectionParams::default();
+    assert_eq!(params.get_role(), ConnectionRole::Unknown);
+    assert!(!params.is_central());
+    assert!(!params.is_peripheral());
+
+    params.set_role(ConnectionRole::Central);
+    assert_eq!(params.role, 1);
+    assert!(params.is_central());
+    assert!(!params.is_peripheral());
+
+    params.set_role(ConnectionRole::Peripheral);
+    assert!(params.is_peripheral());
+
+    // The role is stored with the device and survives a byte round-trip
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Sensor").unwrap();
+    device.update_connection_params(&params);
+    let bytes = bytemuck::bytes_of(&device).to_vec();
+    let restored: BluetoothDeviceInfo = bytemuck::pod_read_unaligned(&bytes);
+    assert!(restored.get_connection_params().is_peripheral());
+
+    // Unrecognized raw values read back as unknown
+    params.role = 0x7F;
+    assert_eq!(params.get_role(), ConnectionRole::Unknown);
+}
+
+#[test]
+fn test_bluetooth_connection_state_check_consistency() {
+    let mut connection_state = BluetoothConnectionState::default();
+    assert!(connection_state.check_consistency().is_ok());
+
+    // Connected without a handle
+    connection_state.set_connected(true);
+    assert!(matches!(
+        connection_state.check_consistency(),
+        Err(Error::CorruptData)
+    ));
+    connection_state.set_connection_handle(Some(ConnHandle::new(0x0040)));
+    assert!(connection_state.check_consistency().is_ok());
+
+    // Authenticated before authentication started
+    connection_state.set_connection_phase(BluetoothConnectionPhase::Connected);
+    connection_state.set_authenticated(true);
+    assert!(matches!(
+        connection_state.check_consistency(),
+        Err(Error::CorruptData)
+    ));
+    connection_state.set_connection_phase(BluetoothConnectionPhase::Authenticating);
+    assert!(connection_state.check_consistency().is_ok());
+
+    // Authenticated while the link is being re-established
+    connection_state.set_connection_phase(BluetoothConnectionPhase::Reconnecting);
+    assert!(matches!(
+        connection_state.check_consistency(),
+        Err(Error::CorruptData)
+    ));
+
+    // Secure phase without an encrypted link
+    connection_state.set_connection_phase(BluetoothConnectionPhase::Ready);
+    assert!(matches!(
+        connection_state.check_consistency(),
+        Err(Error::CorruptData)
+    ));
+
+    let mut device = *connection_state.get_remote_device();
+    let mut security = BluetoothSecurityInfo::default();
+    security.encrypted = 1;
+    device.update_security_info(&security);
+    connection_state.set_remote_device(device);
+    assert!(connection_state.check_consistency().is_ok());
+}
+
+#[test]
+fn test_bluetooth_connection_state_reserved_handle() {
+    let mut params = BluetoothConnectionParams::default();
+    params.connection_handle = bytemuck::cast(0x0F00_u16);
+    let mut device = BluetoothDeviceInfo::default();
+    device.update_connection_params(&params);
+
+    let mut connection_state = BluetoothConnectionState::default();
+    connection_state.set_remote_device(device);
+    assert_eq!(connection_state.get_connection_handle(), None);
+    assert!(matches!(
+        connection_state.check_consistency(),
+        Err(Error::CorruptData)
+    ));
+
+    connection_state.set_connection_handle(Some(ConnHandle::new(0x0EFF)));
+    assert_eq!(
+        connection_state.get_connection_handle(),
+        Some(ConnHandle::new(0x0EFF))
+    );
+    assert!(connection_state.check_consistency().is_ok());
+}
+
+#[test]
+fn test_bluetooth_device_info_ppcp() {
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Sensor").unwrap();
+    let mut params = BluetoothConnectionParams::default();
+    params.connection_interval = 40;
+    params.connection_latency = 4;
+    params.supervision_timeout = 400;
+
+    // Without stored preferences any parameters are acceptable
+    assert_eq!(device.get_ppcp(), None);
+    assert!(device.satisfies_ppcp(&params));
+
+    let ppcp = PeripheralPreferredParams {
+        min_interval: 24,
+        max_interval: 40,
+        latency: 4,
+        timeout: 400,
+    };
+    device.set_ppcp(&ppcp);
+    assert_eq!(device.get_ppcp(), Some(&ppcp));
+    assert!(device.satisfies_ppcp(&params));
+
+    params.connection_interval = 48;
+    assert!(!device.satisfies_ppcp(&params));
+    params.connection_interval = 24;
+    params.connection_latency = 5;
+    assert!(!device.satisfies_ppcp(&params));
+    params.connection_latency = 0;
+    params.supervision_timeout = 300;
+    assert!(!device.satisfies_ppcp(&params));
+
+    // Fields without a preference are not checked
+    device.set_ppcp(&PeripheralPreferredParams {
+        timeout: PeripheralPreferredParams::NO_PREFERENCE,
+        ..ppcp
+    });
+    assert!(device.satisfies_ppcp(&params));
+
+    // Preferences persist with the device record
+    let bytes = bytemuck::bytes_of(&device).to_vec();
+    let restored: BluetoothDeviceInfo = bytemuck::pod_read_unaligned(&bytes);
+    assert_eq!(restored.get_ppcp().unwrap().min_interval, 24);
+
+    device.clear_ppcp();
+    assert_eq!(device.get_ppcp(), None);
+}
+
+#[test]
+fn test_bluetooth_device_list_repair_count() {
+    let mut device_list = BluetoothDeviceList::default();
+    assert_eq!(device_list.count_valid_entries(), 0);
+
+    for i in 0..4u8 {
+        let mac_addr = [0x10, 0x20, 0x30, 0x40, 0x50, i + 1];
+        device_list
+            .add_device(BluetoothDeviceInfo::new(&mac_addr, b"Device").unwrap())
+            .unwrap();
+    }
+    assert_eq!(device_list.count_valid_entries(), 4);
+
+    // Corrupt the count byte as if read from damaged flash
+    let mut bytes = bytemuck::bytes_of(&device_list).to_vec();
+    let count_offset = core::mem::size_of::<BluetoothDeviceList>() - 4;
+    assert_eq!(bytes[count_offset], 4);
+    bytes[count_offset] = 9;
+    let mut corrupted: BluetoothDeviceList = bytemuck::pod_read_unaligned(&bytes);
+    assert_eq!(corrupted.len(), 9);
+    assert_eq!(corrupted.count_valid_entries(), 4);
+
+    corrupted.repair_count();
+    assert_eq!(corrupted.len(), 4);
+    assert_eq!(corrupted.get_device(3).unwrap().get_mac_address()[5], 4);
+
+    // The count stops at the first invalid slot; later slots are cleared
+    bytes[count_offset] = 4;
+    let device_size = core::mem::size_of::<BluetoothDeviceInfo>();
+    bytes[4 + device_size] ^= 0xFF; // Break the magic of slot 1
+    let mut corrupted: BluetoothDeviceList = bytemuck::pod_read_unaligned(&bytes);
+    assert_eq!(corrupted.count_valid_entries(), 3);
+    corrupted.repair_count();
+    assert_eq!(corrupted.len(), 1);
+    assert_eq!(corrupted.count_valid_entries(), 1);
+}
+
+#[test]
+fn test_bluetooth_device_list_sort_by_rssi() {
+    let mut device_list = BluetoothDeviceList::default();
+    device_list.sort_by_rssi(); // Empty list is a no-op
+
+    for (i, rssi) in [-80i8, -127, -40, -80, -128, -60].iter().enumerate() {
+        let mac_addr = [0x10, 0x20, 0x30, 0x40, 0x50, i as u8];
+        let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Device").unwrap();
+        let mut params = BluetoothConnectionParams::default();
+        params.rssi = *rssi;
+        device.update_connection_params(&params);
+        device_list.add_device(device).unwrap();
+    }
+
+    device_list.sort_by_rssi();
+
+    let order: Vec<(i8, u8)> = device_list
+        .enumerate()
+        .map(|(_, device)| {
+            (
+                device.get_connection_params().rssi,
+                device.get_mac_address()[5],
+            )
+        })
+        .collect();
+    // Strongest first, equal values keep their order, unknown last
+    assert_eq!(
+        order,
+        vec![(-40, 2), (-60, 5), (-80, 0), (-80, 3), (-128, 4), (-127, 1)]
+    );
+}
+
+#[test]
+fn test_bluetooth_fsm_transition_relation() {
+    let edges: Vec<_> = all_valid_transitions().collect();
+
+    // Every enumerated edge is accepted by the FSM, every other pair rejected
+    for from in BluetoothConnectionPhase::ALL {
+        for to in BluetoothConnectionPhase::ALL {
+            let mut connection_state = BluetoothConnectionState::default();
+            connection_state.set_connection_phase(from);
+            assert_eq!(
+                connection_state.advance_to_phase(to),
+                edges.contains(&(from, to)),
+                "{from:?} -> {to:?}"
+            );
+        }
+    }
+
+    // No duplicates, and the Idle reset is available everywhere
+    for (i, edge) in edges.iter().enumerate() {
+        assert!(!edges[i + 1..].contains(edge));
+    }
+    for from in BluetoothConnectionPhase::ALL {
+        assert!(edges.contains(&(from, BluetoothConnectionPhase::Idle)));
+    }
+
+    // ALL is in numeric order
+    for (i, phase) in BluetoothConnectionPhase::ALL.iter().enumerate() {
+        assert_eq!(*phase as u8 as usize, i);
+    }
+}
+
+#[test]
+fn test_bluetooth_connection_params_builder() {
+    let params = BluetoothConnectionParams::builder()
+        .interval(6)
+        .latency(0)
+        .timeout(10)
+        .build()
+        .unwrap();
+    assert_eq!(params.connection_interval, 6);
+    assert_eq!(params.connection_latency, 0);
+    assert_eq!(params.supervision_timeout, 10);
+    assert_eq!(params.rssi, -127); // Other fields keep their defaults
+
+    let invalid = |interval, latency, timeout| {
+        matches!(
+            BluetoothConnectionParams::builder()
+                .interval(interval)
+                .latency(latency)
+                .timeout(timeout)
+                .build(),
+            Err(Error::InvalidConnectionParams)
+        )
+    };
+
+    // Range limits
+    assert!(invalid(5, 0, 100));
+    assert!(invalid(3201, 0, 3200));
+    assert!(invalid(6, 500, 3200));
+    assert!(invalid(6, 0, 9));
+    assert!(invalid(6, 0, 3201));
+
+    // 4 * timeout must exceed (1 + latency) * interval
+    assert!(invalid(80, 3, 80)); // 320 == 320
+    assert!(!invalid(80, 3, 81)); // 324 > 320
+    assert!(!invalid(3200, 0, 3200));
+    assert!(invalid(3200, 3, 3200));
+
+    // Unset interval and timeout are rejected
+    assert!(matches!(
+        BluetoothConnectionParams::builder().build(),
+        Err(Error::InvalidConnectionParams)
+    ));
+}
+
+#[test]
+fn test_bluetooth_connection_state_supervision_remaining() {
+    let mut connection_state = BluetoothConnectionState::default();
+    assert_eq!(connection_state.supervision_remaining_ms(1_000), None);
+
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Sensor").unwrap();
+    let mut params = BluetoothConnectionParams::default();
+    params.supervision_timeout = 3200; // 32 s
+    params.last_activity = 1_000;
+    device.update_connection_params(&params);
+    connection_state.set_remote_device(device);
+
+    assert_eq!(
+        connection_state.supervision_remaining_ms(1_000),
+        Some(32_000)
+    );
+    assert_eq!(
+        connection_state.supervision_remaining_ms(1_010),
+        Some(22_000)
+    );
+    assert_eq!(connection_state.supervision_remaining_ms(1_032), Some(0));
+    assert_eq!(connection_state.supervision_remaining_ms(u32::MAX), Some(0));
+
+    // A clock behind the last activity counts as no idle time
+    assert_eq!(connection_state.supervision_remaining_ms(500), Some(32_000));
+}
+
+#[test]
+fn test_bluetooth_device_info_age() {
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Speaker").unwrap();
+    assert_eq!(device.time_since_last_connected(1_000), None);
+    assert_eq!(device.time_since_last_seen(1_000), None);
+
+    device.set_last_connected(900);
+    device.set_last_seen(990);
+    assert_eq!(device.time_since_last_connected(1_000), Some(100));
+    assert_eq!(device.time_since_last_seen(1_000), Some(10));
+
+    // A clock behind the stored timestamps saturates to zero
+    assert_eq!(device.time_since_last_connected(500), Some(0));
+    assert_eq!(device.time_since_last_seen(500), Some(0));
+}
+
+#[test]
+fn test_bluetooth_limits_match_enforced_values() {
+    let mac = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let name = [b'N'; limits::MAX_DEVICE_NAME_LEN];
+    assert!(BluetoothDeviceInfo::new(&mac, &name).is_ok());
+    assert!(matches!(
+        BluetoothDeviceInfo::new(&mac, &[b'N'; limits::MAX_DEVICE_NAME_LEN + 1]),
+        Err(Error::InvalidBluetoothDeviceInfo)
+    ));
+
+    let mut list = BluetoothDeviceList::default();
+    for _ in 0..limits::MAX_DEVICES {
+        list.add_device(BluetoothDeviceInfo::new(&mac, b"Device").unwrap())
+            .unwrap();
+    }
+    assert!(matches!(
+        list.add_device(BluetoothDeviceInfo::new(&mac, b"Device").unwrap()),
+        Err(Error::DeviceListFull)
+    ));
+
+    assert_eq!(ConnHandle::new(limits::MAX_CONN_HANDLE).raw(), 0x0EFF);
+    assert_eq!(BluetoothConnectionPhase::ALL.len(), limits::PHASE_COUNT);
+    assert_eq!(
+        BluetoothSecurityInfo::default().security_level,
+        limits::MIN_SECURITY_LEVEL
+    );
+    assert_eq!(
+        BluetoothSecurityInfo::default().link_key.len(),
+        limits::LINK_KEY_LEN
+    );
+}
+
+#[test]
+fn test_bluetooth_device_list_try_from_iter() {
+    let devices = (0..3u8).map(|i| BluetoothDeviceInfo::new(&[i; 6], b"Device").unwrap());
+    let list = BluetoothDeviceList::try_from_iter(devices).unwrap();
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.get_device(2).unwrap().get_mac_address(), &[2; 6]);
+
+    let empty = BluetoothDeviceList::try_from_iter(core::iter::empty()).unwrap();
+    assert!(empty.is_empty());
+
+    let full = (0..limits::MAX_DEVICES as u8)
+        .map(|i| BluetoothDeviceInfo::new(&[i; 6], b"Device").unwrap());
+    assert_eq!(BluetoothDeviceList::try_from_iter(full).unwrap().len(), 10);
+
+    let too_many = (0..=limits::MAX_DEVICES as u8)
+        .map(|i| BluetoothDeviceInfo::new(&[i; 6], b"Device").unwrap());
+    assert!(matches!(
+        BluetoothDeviceList::try_from_iter(too_many),
+        Err(Error::DeviceListFull)
+    ));
+}
+
+#[test]
+fn test_bluetooth_phase_steps_to_ready() {
+    use BluetoothConnectionPhase::*;
+
+    assert_eq!(Ready.steps_to_ready(), Some(0));
+    assert_eq!(Maintaining.steps_to_ready(), Some(0));
+    assert_eq!(ServiceDiscovery.steps_to_ready(), Some(1));
+    assert_eq!(FullyConnected.steps_to_ready(), Some(1));
+    assert_eq!(Connected.steps_to_ready(), Some(2));
+    assert_eq!(Connecting.steps_to_ready(), Some(3));
+    assert_eq!(Idle.steps_to_ready(), Some(4));
+    assert_eq!(Discovery.steps_to_ready(), Some(4));
+    assert_eq!(Authenticating.steps_to_ready(), Some(3));
+    assert_eq!(Bonding.steps_to_ready(), Some(3));
+    assert_eq!(SettingUpEncryption.steps_to_ready(), Some(2));
+    assert_eq!(Reconnecting.steps_to_ready(), Some(4));
+    assert_eq!(Failed.steps_to_ready(), None);
+    assert_eq!(Disconnecting.steps_to_ready(), None);
+
+    // No single transition can skip ahead of the computed distance
+    for (from, to) in all_valid_transitions().filter(|&(_, to)| to != Idle) {
+        if let (Some(a), Some(b)) = (from.steps_to_ready(), to.steps_to_ready()) {
+            assert!(a <= b + 1, "{from:?} -> {to:?}");
+        }
+    }
+}
+
+#[test]
+fn test_bluetooth_connection_state_clear_connection_flags() {
+    let device =
+        BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], b"Device").unwrap();
+    let mut state = BluetoothConnectionState::default();
+    state.set_remote_device(device);
+    state.set_link_quality(200);
+    state.set_connected(true);
+    state.set_authenticated(true);
+    assert_eq!(state.connection_flags_raw(), 0x03);
+
+    state.clear_connection_flags();
+    assert_eq!(state.connection_flags_raw(), 0);
+    assert!(!state.is_connected());
+    assert!(!state.is_authenticated());
+    assert_eq!(state.get_link_quality(), 200);
+    assert_eq!(
+        state.get_remote_device().get_device_name(),
+        device.get_device_name()
+    );
+}
+
+#[test]
+fn test_bluetooth_device_info_touch() {
+    let mut device =
+        BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], b"Device").unwrap();
+
+    device.touch_seen(1000);
+    assert_eq!(device.time_since_last_seen(1500), Some(500));
+    assert_eq!(device.time_since_last_connected(1500), None);
+    assert!(!device.has_flag(BluetoothDeviceInfo::FLAG_CONNECTED));
+
+    device.touch_connected(2000);
+    device.touch_connected(3000);
+    assert_eq!(device.time_since_last_seen(3500), Some(500));
+    assert_eq!(device.time_since_last_connected(3500), Some(500));
+    assert!(device.has_flag(BluetoothDeviceInfo::FLAG_CONNECTED));
+
+    let mut list = BluetoothDeviceList::default();
+    list.add_device(device).unwrap();
+    assert_eq!(list.total_connection_count(), 2);
+}
+
+#[test]
+fn test_bluetooth_device_info_success_rate() {
+    let mut device =
+        BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], b"Device").unwrap();
+    assert_eq!(device.success_rate_permille(), 0);
+
+    for _ in 0..3 {
+        device.record_attempt();
+    }
+    device.touch_connected(1000);
+    assert_eq!(device.success_rate_permille(), 333);
+
+    device.touch_connected(2000);
+    device.touch_connected(3000);
+    assert_eq!(device.success_rate_permille(), 1000);
+
+    // More successes than recorded attempts are capped
+    device.touch_connected(4000);
+    assert_eq!(device.success_rate_permille(), 1000);
+}
+
+#[test]
+fn test_bluetooth_device_info_alias() {
+    let mut device =
+        BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], b"WH-1000XM4").unwrap();
+    assert!(device.get_alias().is_empty());
+    assert_eq!(device.display_name(), b"WH-1000XM4");
+
+    device.set_alias(b"Dad's Headphones").unwrap();
+    assert_eq!(device.get_alias(), b"Dad's Headphones");
+    assert_eq!(device.display_name(), b"Dad's Headphones");
+    assert_eq!(device.get_device_name(), b"WH-1000XM4");
+
+    device.set_alias(b"Dad").unwrap();
+    assert_eq!(device.get_alias(), b"Dad");
+
+    assert!(matches!(
+        device.set_alias(&[b'A'; 33]),
+        Err(Error::InvalidBluetoothDeviceInfo)
+    ));
+    assert_eq!(device.get_alias(), b"Dad");
+
+    device.set_alias(b"").unwrap();
+    assert_eq!(device.display_name(), b"WH-1000XM4");
+
+    // The alias survives a round trip through the raw bytes
+    device.set_alias(b"Kitchen").unwrap();
+    let restored: BluetoothDeviceInfo = *bytemuck::from_bytes(bytemuck::bytes_of(&device));
+    assert_eq!(restored.display_name(), b"Kitchen");
+
+    assert_eq!(core::mem::size_of::<BluetoothDeviceInfo>(), 240);
+}
+
+#[test]
+fn test_bluetooth_phase_severity() {
+    use BluetoothConnectionPhase::{Disconnecting, Failed, Ready, Reconnecting};
+
+    assert_eq!(Failed.severity(), Severity::Error);
+    assert_eq!(Reconnecting.severity(), Severity::Warn);
+    assert_eq!(Disconnecting.severity(), Severity::Warn);
+    assert_eq!(Ready.severity(), Severity::Info);
+
+    let infos = BluetoothConnectionPhase::ALL
+        .iter()
+        .filter(|phase| phase.severity() == Severity::Info)
+        .count();
+    assert_eq!(infos, BluetoothConnectionPhase::ALL.len() - 3);
+    assert!(Severity::Info < Severity::Warn && Severity::Warn < Severity::Error);
+}
+
+#[test]
+fn test_bluetooth_arbitrary_bytes_never_panic() {
+    // xorshift32: deterministic pseudo-random input without extra dependencies
+    let mut state = 0x9E37_79B9_u32;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state
+    };
+
+    let mut list_bytes = [0; core::mem::size_of::<BluetoothDeviceList>()];
+    let mut state_bytes = [0; core::mem::size_of::<BluetoothConnectionState>()];
+    let mut macs = [[0; 6]; limits::MAX_DEVICES];
+    for _ in 0..64 {
+        list_bytes.iter_mut().for_each(|b| *b = next() as u8);
+        state_bytes.iter_mut().for_each(|b| *b = next() as u8);
+
+        let mut list: BluetoothDeviceList = bytemuck::pod_read_unaligned(&list_bytes);
+        assert!(list.len() <= limits::MAX_DEVICES);
+        for (_, device) in list.enumerate() {
+            assert!(device.get_device_name().len() <= limits::MAX_DEVICE_NAME_LEN);
+            assert!(device.get_pairing_key().len() <= limits::MAX_PAIRING_KEY_LEN);
+            let _ = device.display_name();
+            let _ = device.get_connection_params().rssi_typed();
+            let _ = device.get_security_info().auth_requirements_typed();
+            let _ = device.success_rate_permille();
+            let _ = device.satisfies_ppcp(device.get_connection_params());
+        }
+        let _ = list.collect_macs(&mut macs);
+        let _ = list.find_duplicate();
+        let _ = list.most_connected_device();
+        let _ = list.total_connection_count();
+        list.sort_by_rssi();
+
+        // Mutations run directly on the arbitrary contents
+        let _ = list.remove_device(0);
+        let _ = list.undo_remove();
+        let _ = list.add_device(BluetoothDeviceInfo::default());
+        list.compact(|device| device.is_valid());
+        list.clear_last_removed();
+        list.repair_count();
+
+        let mut connection: BluetoothConnectionState = bytemuck::pod_read_unaligned(&state_bytes);
+        if let Some(handle) = connection.get_connection_handle() {
+            assert!(ConnHandle::is_valid_raw(handle.raw()));
+        }
+        let phase = connection.get_connection_phase();
+        let _ = phase.steps_to_ready();
+        let _ = connection.summary();
+        let _ = connection.check_consistency();
+        let _ = connection.supervision_remaining_ms(next());
+        let _ = connection.get_remote_device().display_name();
+        let _ = connection.advance_to_phase(BluetoothConnectionPhase::Ready);
+    }
+}
+
+#[test]
+fn test_bluetooth_connection_state_sync_flags_from_phase() {
+    use BluetoothConnectionPhase::*;
+
+    let mut state = BluetoothConnectionState::default();
+    state.set_connection_handle(Some(ConnHandle::new(0x0040)));
+
+    state.set_connection_phase(Ready);
+    state.sync_flags_from_phase();
+    assert!(state.is_connected());
+    assert!(state.is_authenticated());
+    assert!(state.is_link_encrypted());
+
+    state.set_connection_phase(Connected);
+    state.sync_flags_from_phase();
+    assert!(state.is_connected());
+    assert!(!state.is_authenticated());
+    assert!(!state.is_link_encrypted());
+
+    state.set_connection_phase(Bonding);
+    state.sync_flags_from_phase();
+    assert!(state.is_authenticated());
+    assert!(!state.is_link_encrypted());
+
+    state.set_connection_phase(Idle);
+    state.sync_flags_from_phase();
+    assert_eq!(state.connection_flags_raw(), 0);
+    assert!(!state.is_link_encrypted());
+
+    // Synced flags always pass the consistency check
+    for phase in BluetoothConnectionPhase::ALL {
+        state.set_connection_phase(phase);
+        state.sync_flags_from_phase();
+        assert!(state.check_consistency().is_ok(), "{phase:?}");
+    }
+}
+
+#[test]
+fn test_bluetooth_device_list_diff() {
+    let device = |last: u8| {
+        BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, last], b"Device").unwrap()
+    };
+
+    let previous = BluetoothDeviceList::try_from_iter([device(1), device(2), device(3)]).unwrap();
+    let current = BluetoothDeviceList::try_from_iter([device(2), device(4), device(5)]).unwrap();
+
+    let mut added = [[0; 6]; limits::MAX_DEVICES];
+    let mut removed = [[0; 6]; limits::MAX_DEVICES];
+    let (n_added, n_removed) = current.diff(&previous, &mut added, &mut removed).unwrap();
+    assert_eq!(n_added, 2);
+    assert_eq!(n_removed, 2);
+    assert_eq!(added[0][5], 4);
+    assert_eq!(added[1][5], 5);
+    assert_eq!(removed[0][5], 1);
+    assert_eq!(removed[1][5], 3);
+
+    // Identical snapshots yield no changes
+    assert_eq!(
+        current.diff(&current, &mut added, &mut removed).unwrap(),
+        (0, 0)
+    );
+
+    let mut small = [[0; 6]; 1];
+    assert!(matches!(
+        current.diff(&previous, &mut small, &mut removed),
+        Err(Error::BufferTooSmall)
+    ));
+    assert!(matches!(
+        current.diff(&previous, &mut added, &mut small),
+        Err(Error::BufferTooSmall)
+    ));
+}
+
+#[test]
+fn test_bluetooth_conn_handle_is_valid_raw() {
+    const { assert!(ConnHandle::is_valid_raw(0x0EFF)) };
+    assert!(ConnHandle::is_valid_raw(0x0000));
+    assert!(!ConnHandle::is_valid_raw(0x0F00));
+    assert!(!ConnHandle::is_valid_raw(u16::MAX));
+
+    let classify = |raw: u16| match raw {
+        0 => "none",
+        raw if ConnHandle::is_valid_raw(raw) => "valid",
+        _ => "reserved",
+    };
+    assert_eq!(classify(0x0042), "valid");
+    assert_eq!(classify(0x0FFF), "reserved");
+}
+
+#[test]
+fn test_bluetooth_connection_params_att_mtu() {
+    let mut params = BluetoothConnectionParams::default();
+    assert_eq!(params.att_mtu(), BluetoothConnectionParams::ATT_MTU_MIN);
+
+    params.set_att_mtu(247).unwrap();
+    assert_eq!(params.att_mtu(), 247);
+    params.set_att_mtu(517).unwrap();
+    assert_eq!(params.att_mtu(), 517);
+
+    assert!(matches!(
+        params.set_att_mtu(22),
+        Err(Error::InvalidConnectionParams)
+    ));
+    assert!(matches!(
+        params.set_att_mtu(518),
+        Err(Error::InvalidConnectionParams)
+    ));
+    assert_eq!(params.att_mtu(), 517);
+
+    // Zeroed storage reads as the minimum MTU
+    let zeroed: BluetoothConnectionParams = bytemuck::Zeroable::zeroed();
+    assert_eq!(zeroed.att_mtu(), 23);
+
+    // The MTU persists with the device record
+    let mut device =
+        BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], b"Device").unwrap();
+    device.update_connection_params(&params);
+    let restored: BluetoothDeviceInfo = bytemuck::pod_read_unaligned(bytemuck::bytes_of(&device));
+    assert_eq!(restored.get_connection_params().att_mtu(), 517);
+    assert_eq!(core::mem::size_of::<BluetoothConnectionParams>(), 24);
+}
+
+#[test]
+fn test_bluetooth_device_info_has_valid_mac() {
+    assert!(is_valid_unicast_mac(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]));
+    assert!(is_valid_unicast_mac(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]));
+    assert!(!is_valid_unicast_mac(&[0x00; 6]));
+    assert!(!is_valid_unicast_mac(&[0xFF; 6]));
+
+    let device =
+        BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], b"Device").unwrap();
+    assert!(device.has_valid_mac());
+    assert!(device.is_valid());
+
+    let broadcast = BluetoothDeviceInfo::new(&[0xFF; 6], b"Broadcast").unwrap();
+    assert!(!broadcast.has_valid_mac());
+    assert!(!broadcast.is_valid());
+
+    let unset = BluetoothDeviceInfo::default();
+    assert!(!unset.has_valid_mac());
+    assert!(!unset.is_valid());
+}
+
+#[test]
+fn test_bluetooth_device_info_remaining_capacity() {
+    let mut device =
+        BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], b"Speaker").unwrap();
+    assert_eq!(device.name_remaining(), limits::MAX_DEVICE_NAME_LEN - 7);
+    assert_eq!(device.pairing_key_remaining(), limits::MAX_PAIRING_KEY_LEN);
+
+    device.set_pairing_key(b"123456").unwrap();
+    assert_eq!(
+        device.pairing_key_remaining(),
+        limits::MAX_PAIRING_KEY_LEN - 6
+    );
+
+    device.set_device_name(&[b'N'; 32]).unwrap();
+    assert_eq!(device.name_remaining(), 0);
+}
+
+#[test]
+fn test_bluetooth_connection_state_into_remote_device() {
+    let mut device =
+        BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], b"Device").unwrap();
+    device.set_pairing_key(b"123456").unwrap();
+
+    let mut state = BluetoothConnectionState::default();
+    state.set_remote_device(device);
+    state.set_connection_phase(BluetoothConnectionPhase::Ready);
+
+    let mut list = BluetoothDeviceList::default();
+    list.add_device(state.into_remote_device()).unwrap();
+    assert_eq!(list.get_device(0).unwrap().get_device_name(), b"Device");
+    assert_eq!(list.get_device(0).unwrap().get_pairing_key(), b"123456");
+}
+
+#[test]
+fn test_bluetooth_device_info_codec_support() {
+    let mut device =
+        BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], b"Headphones").unwrap();
+    device.set_class_of_device(&[0x04, 0x10, 0x24]); // Audio major class
+    assert_eq!(
+        device.get_device_type(),
+        BluetoothDeviceInfo::DEVICE_TYPE_AUDIO
+    );
+
+    // Unknown by default
+    assert!(device.codec_support().is_empty());
+    assert!(!device.supports_codec(Codec::SBC));
+
+    device.set_codec_support(Codec::SBC | Codec::LDAC);
+    assert!(device.supports_codec(Codec::SBC));
+    assert!(device.supports_codec(Codec::LDAC));
+    assert!(device.supports_codec(Codec::SBC | Codec::LDAC));
+    assert!(!device.supports_codec(Codec::AAC));
+    assert!(!device.supports_codec(Codec::SBC | Codec::APTX));
+    assert!(!device.supports_codec(Codec::empty()));
+
+    device.set_codec_support(Codec::AAC);
+    assert_eq!(device.codec_support(), Codec::AAC);
+
+    let restored: BluetoothDeviceInfo = bytemuck::pod_read_unaligned(bytemuck::bytes_of(&device));
+    assert!(restored.supports_codec(Codec::AAC));
+    assert_eq!(core::mem::size_of::<BluetoothDeviceInfo>(), 240);
+}
+
+#[test]
+fn test_bluetooth_device_info_display_name_or_mac() {
+    let mut out = [0; 32];
+
+    let mut device =
+        BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9a, 0x0c], b"Speaker").unwrap();
+    let len = device.display_name_or_mac(&mut out).unwrap();
+    assert_eq!(&out[..len], b"Speaker");
+
+    device.set_alias(b"Kitchen").unwrap();
+    let len = device.display_name_or_mac(&mut out).unwrap();
+    assert_eq!(&out[..len], b"Kitchen");
+
+    let unnamed = BluetoothDeviceInfo::new(&[0x12, 0x34, 0x56, 0x78, 0x9a, 0x0c], b"").unwrap();
+    let len = unnamed.display_name_or_mac(&mut out).unwrap();
+    assert_eq!(&out[..len], b"12:34:56:78:9A:0C");
+
+    let mut small = [0; 16];
+    assert!(matches!(
+        unnamed.display_name_or_mac(&mut small),
+        Err(Error::BufferTooSmall)
+    ));
+    let mut tiny = [0; 6];
+    assert!(matches!(
+        device.display_name_or_mac(&mut tiny),
+        Err(Error::BufferTooSmall)
+    ));
+}
+
+#[test]
+fn test_bluetooth_conn_handle_try_new() {
+    assert_eq!(ConnHandle::try_new(0x0EFF).unwrap().raw(), 0x0EFF);
+    assert_eq!(ConnHandle::try_new(0).unwrap(), ConnHandle::default());
+    assert!(matches!(
+        ConnHandle::try_new(0x0F00),
+        Err(Error::InvalidConnectionHandle)
+    ));
+    assert!(matches!(
+        ConnHandle::try_new(u16::MAX),
+        Err(Error::InvalidConnectionHandle)
+    ));
+
+    for raw in [0, 1, 0x0042, 0x0EFE, 0x0EFF, 0x0F00, 0x0FFF, u16::MAX] {
+        let from_try_new = ConnHandle::try_new(raw).ok();
+        assert_eq!(ConnHandle::try_from(raw).ok(), from_try_new);
+        assert_eq!(from_try_new.is_some(), ConnHandle::is_valid_raw(raw));
+    }
+}
+
+#[test]
+fn test_bluetooth_device_list_find_device_by_mac() {
+    let mut device_list = BluetoothDeviceList::default();
+    let macs = [
+        [0x12, 0x34, 0x56, 0x78, 0x9A, 0x01],
+        [0x12, 0x34, 0x56, 0x78, 0x9A, 0x02],
+        [0x12, 0x34, 0x56, 0x78, 0x9A, 0x03],
+    ];
+    for (mac, name) in macs.iter().zip([b"First", b"Secnd", b"Third"]) {
+        device_list
+            .add_device(BluetoothDeviceInfo::new(mac, name).unwrap())
+            .unwrap();
+    }
+
+    let found = device_list.find_device_by_mac(&macs[1]).unwrap();
+    assert_eq!(found.get_mac_address(), &macs[1]);
+    assert_eq!(found.get_device_name(), b"Secnd");
+
+    device_list
+        .find_device_by_mac_mut(&macs[1])
+        .unwrap()
+        .set_device_name(b"Second")
+        .unwrap();
+    assert_eq!(
+        device_list.get_device(1).unwrap().get_device_name(),
+        b"Second"
+    );
+
+    let unknown = [0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF];
+    assert!(device_list.find_device_by_mac(&unknown).is_none());
+    assert!(device_list.find_device_by_mac_mut(&unknown).is_none());
+
+    // Unused slots are not searched
+    assert!(
+        BluetoothDeviceList::default()
+            .find_device_by_mac(&[0; 6])
+            .is_none()
+    );
+}
+
+#[test]
+fn test_bluetooth_device_list_remove_clears_vacated_slot() {
+    let mut device_list = BluetoothDeviceList::default();
+    let mut security = BluetoothSecurityInfo::default();
+    security.link_key = [0xA5; 16];
+    security.link_key_valid = 1;
+    for i in 0..limits::MAX_DEVICES {
+        let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, i as u8];
+        let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Device").unwrap();
+        device.set_pairing_key(b"pairing_key").unwrap();
+        device.update_security_info(&security);
+        device_list.add_device(device).unwrap();
+    }
+
+    device_list.remove_device(0).unwrap();
+    assert_eq!(device_list.len(), limits::MAX_DEVICES - 1);
+
+    // The old last slot follows the 4-byte magic and the remaining devices
+    let device_size = core::mem::size_of::<BluetoothDeviceInfo>();
+    let offset = 4 + (limits::MAX_DEVICES - 1) * device_size;
+    let slot: BluetoothDeviceInfo = bytemuck::pod_read_unaligned(
+        &bytemuck::bytes_of(&device_list)[offset..offset + device_size],
+    );
+    assert_eq!(slot.get_security_info().link_key, [0; 16]);
+    assert_eq!(slot.get_pairing_key(), b"");
+    assert_eq!(
+        bytemuck::bytes_of(&slot),
+        bytemuck::bytes_of(&BluetoothDeviceInfo::default())
+    );
+}
+
+#[test]
+fn test_bluetooth_device_list_iter() {
+    let mut device_list = BluetoothDeviceList::default();
+    assert_eq!(device_list.iter().count(), 0);
+
+    let names: [&[u8]; 3] = [b"Speaker", b"Keyboard", b"Mouse"];
+    for (i, name) in names.iter().enumerate() {
+        let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, i as u8];
+        device_list
+            .add_device(BluetoothDeviceInfo::new(&mac_addr, name).unwrap())
+            .unwrap();
+    }
+
+    let collected: Vec<&[u8]> = device_list
+        .iter()
+        .map(BluetoothDeviceInfo::get_device_name)
+        .collect();
+    assert_eq!(collected, names);
+
+    let mut count = 0;
+    for device in &device_list {
+        assert_eq!(device.get_device_name(), names[count]);
+        count += 1;
+    }
+    assert_eq!(count, device_list.len());
+
+    for device in device_list.iter_mut() {
+        device.touch_seen(1_700_000_000);
+    }
+    for device in &mut device_list {
+        device.add_flag(BluetoothDeviceInfo::FLAG_TRUSTED);
+    }
+    assert!(
+        device_list.iter().all(
+            |device| device.time_since_last_seen(1_700_000_010) == Some(10) && device.is_trusted()
+        )
+    );
+}
+
+#[test]
+fn test_bluetooth_device_list_remove_device_by_mac() {
+    let mut device_list = BluetoothDeviceList::default();
+    let macs = [
+        [0x12, 0x34, 0x56, 0x78, 0x9A, 0x01],
+        [0x12, 0x34, 0x56, 0x78, 0x9A, 0x02],
+        [0x12, 0x34, 0x56, 0x78, 0x9A, 0x03],
+    ];
+    for mac in &macs {
+        let mut device = BluetoothDeviceInfo::new(mac, b"Device").unwrap();
+        device.set_pairing_key(b"key").unwrap();
+        device_list.add_device(device).unwrap();
+    }
+
+    device_list.remove_device_by_mac(&macs[1]).unwrap();
+    assert_eq!(device_list.len(), 2);
+    let remaining: Vec<[u8; 6]> = device_list.iter().map(|d| d.mac_address()).collect();
+    assert_eq!(remaining, [macs[0], macs[2]]);
+    assert!(device_list.find_device_by_mac(&macs[1]).is_none());
+
+    // The vacated slot is cleared
+    let device_size = core::mem::size_of::<BluetoothDeviceInfo>();
+    let offset = 4 + 2 * device_size;
+    assert_eq!(
+        &bytemuck::bytes_of(&device_list)[offset..offset + device_size],
+        bytemuck::bytes_of(&BluetoothDeviceInfo::default())
+    );
+
+    assert!(matches!(
+        device_list.remove_device_by_mac(&macs[1]),
+        Err(Error::NotFound)
+    ));
+    assert_eq!(device_list.len(), 2);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_bluetooth_serde_round_trip() {
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Headphones").unwrap();
+    device.set_pairing_key(&[0x5A; 64]).unwrap();
+    device.set_alias(b"Living Room").unwrap();
+    device.add_flag(BluetoothDeviceInfo::FLAG_PAIRED);
+    let mut security = BluetoothSecurityInfo::default();
+    security.link_key = [0xA5; 16];
+    security.link_key_valid = 1;
+    device.update_security_info(&security);
+    let mut params = BluetoothConnectionParams::default();
+    params.connection_handle = ConnHandle::new(0x0042);
+    device.update_connection_params(&params);
+
+    let json = serde_json::to_string(&device).unwrap();
+    let restored: BluetoothDeviceInfo = serde_json::from_str(&json).unwrap();
+    assert_eq!(bytemuck::bytes_of(&restored), bytemuck::bytes_of(&device));
+
+    let mut device_list = BluetoothDeviceList::default();
+    device_list.add_device(device).unwrap();
+    let json = serde_json::to_string(&device_list).unwrap();
+    let restored: BluetoothDeviceList = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        bytemuck::bytes_of(&restored),
+        bytemuck::bytes_of(&device_list)
+    );
+
+    // Connection handles are range-checked on deserialization
+    assert_eq!(
+        serde_json::to_string(&ConnHandle::new(0x0EFF)).unwrap(),
+        "3839"
+    );
+    assert!(serde_json::from_str::<ConnHandle>("3840").is_err());
+}
+
+#[test]
+fn test_bluetooth_connection_phase_try_from_u8() {
+    for value in 0..=13u8 {
+        let phase = BluetoothConnectionPhase::try_from(value).unwrap();
+        assert_eq!(phase as u8, value);
+    }
+    for value in [14, 200, u8::MAX] {
+        assert!(matches!(
+            BluetoothConnectionPhase::try_from(value),
+            Err(Error::InvalidConnectionPhase)
+        ));
+    }
+
+    let mut state = BluetoothConnectionState::default();
+    state.set_connection_phase(BluetoothConnectionPhase::Ready);
+    assert_eq!(
+        state.try_get_connection_phase().unwrap(),
+        BluetoothConnectionPhase::Ready
+    );
+
+    // The phase byte follows the magic, the remote device, the connection
+    // flags and the link quality
+    let offset = 4 + core::mem::size_of::<BluetoothDeviceInfo>() + 2;
+    let mut bytes = bytemuck::bytes_of(&state).to_vec();
+    assert_eq!(bytes[offset], BluetoothConnectionPhase::Ready as u8);
+    bytes[offset] = 200;
+    let corrupt: BluetoothConnectionState = bytemuck::pod_read_unaligned(&bytes);
+    assert!(matches!(
+        corrupt.try_get_connection_phase(),
+        Err(Error::InvalidConnectionPhase)
+    ));
+    assert_eq!(
+        corrupt.get_connection_phase(),
+        BluetoothConnectionPhase::Idle
+    );
+}
@@ -17,8 +17,8 @@ fn test_device_info_default() {
     let device = DeviceInfo::default();
 
     assert!(device.is_valid());
-    assert_eq!(device.get_hardware_id(), &[0u8; 32]);
-    assert_eq!(device.get_secret(), &[0u8; 128]);
+    assert_eq!(device.get_hardware_id(), b"");
+    assert_eq!(device.get_secret(), b"");
 }
 
 #[test]
@@ -92,9 +92,10 @@ fn test_device_info_empty_fields() {
     let device = DeviceInfo::new(b"", b"").unwrap();
     assert!(device.is_valid());
 
-    // The arrays should still be valid, just with empty content at the start
-    assert_eq!(device.get_hardware_id()[0], 0);
-    assert_eq!(device.get_secret()[0], 0);
+    assert!(device.get_hardware_id().is_empty());
+    assert!(device.get_secret().is_empty());
+    assert_eq!(device.hardware_id_len(), 0);
+    assert_eq!(device.secret_len(), 0);
 }
 
 #[test]
@@ -127,7 +128,7 @@ fn test_device_info_partial_update() {
 #[test]
 fn test_device_info_memory_layout() {
     // Test that the structure has the expected size for embedded use
-    let expected_size = 4 + 32 + 128; // magic + hardware_id + secret
+    let expected_size = 4 + 32 + 128 + 1 + 1 + 1 + 1 + 4; // magic + hardware_id + secret + locked + hardware_id_len + secret_len + padding + crc32
     assert_eq!(core::mem::size_of::<DeviceInfo>(), expected_size);
 
     // Ensure proper alignment
@@ -189,3 +190,282 @@ fn test_device_info_boundary_conditions() {
         Err(Error::IdentityLengthExceeded)
     ));
 }
+
+#[test]
+fn test_device_info_is_provisioned() {
+    let mut device = DeviceInfo::default();
+    assert!(device.is_valid());
+    assert!(!device.is_provisioned());
+
+    device.set_hardware_id(b"RENIK-TEST").unwrap();
+    assert!(!device.is_provisioned()); // Secret still missing
+
+    device.set_secret(b"secret").unwrap();
+    assert!(device.is_provisioned());
+
+    assert!(!DeviceInfo::new(b"", b"secret").unwrap().is_provisioned());
+}
+
+#[test]
+fn test_device_info_lock() {
+    let mut device = DeviceInfo::new(b"RENIK-TEST", b"secret").unwrap();
+    assert!(!device.is_locked());
+
+    device.lock();
+    assert!(device.is_locked());
+    assert!(matches!(
+        device.set_hardware_id(b"OTHER-ID"),
+        Err(Error::ConfigLocked)
+    ));
+    assert!(matches!(
+        device.set_secret(b"other"),
+        Err(Error::ConfigLocked)
+    ));
+    assert_eq!(&device.get_hardware_id()[..10], b"RENIK-TEST");
+    assert_eq!(&device.get_secret()[..6], b"secret");
+
+    // Lock state survives a byte round-trip
+    let bytes = bytemuck::bytes_of(&device).to_vec();
+    let restored: DeviceInfo = bytemuck::pod_read_unaligned(&bytes);
+    assert!(restored.is_locked());
+}
+
+#[test]
+fn test_device_info_rotate_secret() {
+    let mut device = DeviceInfo::new(b"RENIK-TEST", b"original_secret").unwrap();
+
+    let mut old_secret = device.rotate_secret(b"new_secret").unwrap();
+    assert_eq!(&old_secret[..15], b"original_secret");
+    assert_eq!(&device.get_secret()[..10], b"new_secret");
+    // The tail of the old secret is not carried over
+    assert!(device.get_secret()[10..].iter().all(|&b| b == 0));
+
+    // Failed rotations leave the secret untouched
+    let long_secret = vec![b'X'; 129];
+    assert!(matches!(
+        device.rotate_secret(&long_secret),
+        Err(Error::IdentityLengthExceeded)
+    ));
+    assert_eq!(&device.get_secret()[..10], b"new_secret");
+
+    // Roll back using the returned buffer
+    device.set_secret(&old_secret).unwrap();
+    assert_eq!(&device.get_secret()[..15], b"original_secret");
+    old_secret.fill(0);
+
+    device.lock();
+    assert!(matches!(
+        device.rotate_secret(b"locked"),
+        Err(Error::ConfigLocked)
+    ));
+}
+
+#[test]
+fn test_device_info_new_zeroed_with_magic() {
+    let device = DeviceInfo::new_zeroed_with_magic();
+    assert!(device.is_valid());
+    assert!(!device.is_provisioned());
+    assert!(bytemuck::bytes_of(&device)[4..].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn test_device_info_matches_identity() {
+    let device = DeviceInfo::new(b"RENIK-01JY1863M2V0S776", b"device_secret").unwrap();
+
+    assert!(device.matches_hardware_id(b"RENIK-01JY1863M2V0S776"));
+    assert!(!device.matches_hardware_id(b"RENIK-01JY1863M2V0S777"));
+    assert!(!device.matches_hardware_id(b"RENIK-01JY1863M2V0S77"));
+    assert!(!device.matches_hardware_id(&[b'X'; 33]));
+
+    assert!(device.matches_identity(b"RENIK-01JY1863M2V0S776", b"device_secret"));
+    assert!(!device.matches_identity(b"RENIK-01JY1863M2V0S776", b"other_secret"));
+    assert!(!device.matches_identity(b"RENIK-CLONE", b"device_secret"));
+    assert!(!device.matches_identity(b"RENIK-01JY1863M2V0S776", &[b'X'; 129]));
+}
+
+#[test]
+fn test_device_info_identity_bytes() {
+    let hardware_id = b"RENIK-01JY1863M2V0S776";
+    let secret = b"never_leaves_the_device";
+    let device = DeviceInfo::new(hardware_id, secret).unwrap();
+
+    let mut out = [0xAA; 64];
+    let len = device.identity_bytes(&mut out).unwrap();
+    assert_eq!(len, 4 + 1 + hardware_id.len());
+    assert_eq!(&out[..4], &0x0044_4556u32.to_le_bytes());
+    assert_eq!(usize::from(out[4]), hardware_id.len());
+    assert_eq!(&out[5..len], hardware_id);
+    assert!(out[len..].iter().all(|&b| b == 0xAA));
+    assert!(!out[..len].windows(secret.len()).any(|w| w == secret));
+
+    let mut small = [0; 26];
+    assert!(matches!(
+        device.identity_bytes(&mut small),
+        Err(Error::BufferTooSmall)
+    ));
+
+    let empty = DeviceInfo::default();
+    assert_eq!(empty.identity_bytes(&mut out).unwrap(), 5);
+    assert_eq!(out[4], 0);
+}
+
+#[test]
+fn test_device_info_lengths() {
+    let hardware_id = b"RENIK-01JY1863M2V0S776";
+    let secret = b"device_secret";
+    let mut device = DeviceInfo::new(hardware_id, secret).unwrap();
+
+    assert_eq!(device.get_hardware_id(), hardware_id);
+    assert_eq!(device.get_secret(), secret);
+    assert_eq!(device.hardware_id_len(), hardware_id.len());
+    assert_eq!(device.secret_len(), secret.len());
+
+    // Trailing zeros are significant now that the length is stored
+    let padded = DeviceInfo::new(b"ID\0\0", b"secret").unwrap();
+    assert_eq!(padded.get_hardware_id(), b"ID\0\0");
+    assert!(padded.matches_hardware_id(b"ID\0\0"));
+    assert!(!padded.matches_hardware_id(b"ID"));
+
+    device.set_hardware_id(b"SHORT").unwrap();
+    assert_eq!(device.get_hardware_id(), b"SHORT");
+    assert_eq!(device.hardware_id_len(), 5);
+
+    // Lengths survive a byte round-trip
+    let restored: DeviceInfo = bytemuck::pod_read_unaligned(bytemuck::bytes_of(&device));
+    assert_eq!(restored.get_hardware_id(), b"SHORT");
+    assert_eq!(restored.get_secret(), secret);
+}
+
+#[test]
+fn test_device_info_setters_clear_stale_bytes() {
+    let mut device = DeviceInfo::new(b"LONG-HARDWARE-IDENTIFIER", &[b'S'; 20]).unwrap();
+    device.set_secret(b"short").unwrap();
+    device.set_hardware_id(b"ID").unwrap();
+
+    // The secret buffer follows the 4-byte magic and the 32-byte hardware ID
+    let bytes = bytemuck::bytes_of(&device);
+    assert_eq!(&bytes[4..6], b"ID");
+    assert!(bytes[6..36].iter().all(|&b| b == 0));
+    assert_eq!(&bytes[36..41], b"short");
+    assert!(bytes[41..56].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn test_device_info_crc() {
+    let mut device = DeviceInfo::new(b"RENIK-01JY1863M2V0S776", b"device_secret").unwrap();
+    assert!(!device.verify());
+
+    device.finalize();
+    assert!(device.verify());
+
+    // Known answer (zlib's crc32) on little-endian targets: magic followed by zeros
+    #[cfg(target_endian = "little")]
+    assert_eq!(
+        DeviceInfo::new_zeroed_with_magic().compute_crc(),
+        0x04AB_20DE
+    );
+
+    // A flipped bit in the secret is detected
+    let mut bytes = bytemuck::bytes_of(&device).to_vec();
+    bytes[4 + 32 + 3] ^= 0x01;
+    let corrupt: DeviceInfo = bytemuck::pod_read_unaligned(&bytes);
+    assert!(corrupt.is_valid());
+    assert!(!corrupt.verify());
+
+    // Modifications require a new finalize
+    device.set_secret(b"rotated").unwrap();
+    assert!(!device.verify());
+    device.lock();
+    device.finalize();
+    assert!(device.verify());
+
+    let restored: DeviceInfo = bytemuck::pod_read_unaligned(bytemuck::bytes_of(&device));
+    assert!(restored.verify());
+}
+
+#[test]
+fn test_device_info_from_bytes() {
+    let mut device = DeviceInfo::new(b"RENIK-01JY1863M2V0S776", b"device_secret").unwrap();
+    device.finalize();
+
+    let restored = DeviceInfo::from_bytes(bytemuck::bytes_of(&device)).unwrap();
+    assert_eq!(restored.get_hardware_id(), b"RENIK-01JY1863M2V0S776");
+    assert_eq!(restored.get_secret(), b"device_secret");
+    assert!(restored.verify());
+
+    // Wrong length, bad magic and oversized length fields are rejected
+    let mut bytes = bytemuck::bytes_of(&device).to_vec();
+    assert!(matches!(
+        DeviceInfo::from_bytes(&bytes[1..]),
+        Err(Error::CorruptData)
+    ));
+    bytes.push(0xFF);
+    assert!(matches!(
+        DeviceInfo::from_bytes(&bytes),
+        Err(Error::CorruptData)
+    ));
+    assert!(DeviceInfo::from_bytes_relaxed(&bytes).is_ok());
+    assert!(matches!(
+        DeviceInfo::from_bytes_relaxed(&bytes[..bytes.len() - 2]),
+        Err(Error::CorruptData)
+    ));
+
+    bytes[0] ^= 0xFF;
+    assert!(matches!(
+        DeviceInfo::from_bytes_relaxed(&bytes),
+        Err(Error::CorruptData)
+    ));
+    bytes[0] ^= 0xFF;
+    bytes[4 + 32 + 128 + 1] = 33; // hardware_id_len
+    assert!(matches!(
+        DeviceInfo::from_bytes_relaxed(&bytes),
+        Err(Error::CorruptData)
+    ));
+}
+
+#[test]
+fn test_device_info_arbitrary_bytes_never_panic() {
+    // xorshift32: deterministic pseudo-random input without extra dependencies
+    let mut state = 0x6C07_8965_u32;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state
+    };
+
+    let mut bytes = [0; core::mem::size_of::<DeviceInfo>() + 1];
+    let mut out = [0; 64];
+    for round in 0..512 {
+        bytes.iter_mut().for_each(|b| *b = next() as u8);
+        if round % 2 == 0 {
+            bytes[..4].copy_from_slice(&0x0044_4556_u32.to_ne_bytes());
+        }
+        if round % 4 == 0 {
+            // Plausible lengths, so that some inputs are accepted
+            bytes[4 + 32 + 128 + 1] %= 33;
+            bytes[4 + 32 + 128 + 2] %= 129;
+        }
+
+        let exact = &bytes[..core::mem::size_of::<DeviceInfo>()];
+        let parsed = [
+            DeviceInfo::from_bytes(exact),
+            DeviceInfo::from_bytes_relaxed(&bytes),
+        ];
+        for mut device in parsed.into_iter().flatten() {
+            assert!(device.is_valid());
+            assert!(device.get_hardware_id().len() <= 32);
+            assert!(device.get_secret().len() <= 128);
+            let _ = device.is_provisioned();
+            let _ = device.matches_identity(b"id", b"secret");
+            let _ = device.verify();
+            let _ = device.identity_bytes(&mut out);
+            let _ = device.rotate_secret(b"rotated");
+        }
+
+        // Unchecked reads keep every getter in bounds
+        let device: DeviceInfo = bytemuck::pod_read_unaligned(exact);
+        assert!(device.get_hardware_id().len() <= 32);
+        assert!(device.get_secret().len() <= 128);
+    }
+}
@@ -0,0 +1,94 @@
+use renik::{
+    BluetoothConnectionState, BluetoothDeviceInfo, BluetoothDeviceList, BluetoothSecurityInfo,
+    DeviceInfo, SecureErase, WifiConfig,
+};
+
+fn assert_erased<T: SecureErase + bytemuck::Pod + Default>(value: &mut T) {
+    assert_ne!(bytemuck::bytes_of(value), bytemuck::bytes_of(&T::default()));
+    value.secure_erase();
+    assert_eq!(bytemuck::bytes_of(value), bytemuck::bytes_of(&T::default()));
+}
+
+fn paired_device() -> BluetoothDeviceInfo {
+    let mac_addr = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    let mut device = BluetoothDeviceInfo::new(&mac_addr, b"Headphones").unwrap();
+    device.set_pairing_key(b"pairing_key").unwrap();
+
+    let mut security = BluetoothSecurityInfo::default();
+    security.link_key = [0xA5; 16];
+    security.link_key_valid = 1;
+    device.update_security_info(&security);
+    device
+}
+
+#[test]
+fn test_secure_erase_wifi_config() {
+    let mut config = WifiConfig::new(b"MyNetwork", b"password123").unwrap();
+    assert_erased(&mut config);
+
+    assert!(config.is_valid());
+    assert_eq!(config.get_ssid(), b"");
+    assert_eq!(config.get_password(), b"");
+}
+
+#[test]
+fn test_secure_erase_device_info() {
+    let mut device = DeviceInfo::new(b"RENIK-TEST", b"device_secret").unwrap();
+    device.lock();
+    assert_erased(&mut device);
+
+    assert!(device.is_valid());
+    assert!(!device.is_locked());
+    assert!(!device.is_provisioned());
+}
+
+#[test]
+fn test_secure_erase_bluetooth() {
+    let mut device = paired_device();
+    let mut security = *device.get_security_info();
+    assert_erased(&mut device);
+    assert_eq!(device.get_pairing_key(), b"");
+    assert_eq!(device.get_security_info().link_key, [0; 16]);
+
+    assert_erased(&mut security);
+    assert_eq!(security.link_key_valid, 0);
+
+    let mut device_list = BluetoothDeviceList::default();
+    device_list.add_device(paired_device()).unwrap();
+    device_list.add_device(paired_device()).unwrap();
+    device_list.remove_device(0).unwrap();
+    assert_erased(&mut device_list);
+    assert!(device_list.is_empty());
+    assert!(device_list.last_removed().is_none());
+
+    let mut connection_state = BluetoothConnectionState::default();
+    connection_state.set_remote_device(paired_device());
+    connection_state.set_connected(true);
+    assert_erased(&mut connection_state);
+    assert!(!connection_state.is_connected());
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_zeroize_on_drop() {
+    use core::mem::MaybeUninit;
+    use renik::ZeroizeOnDrop;
+
+    let mut config = ZeroizeOnDrop::new(WifiConfig::new(b"MyNetwork", b"password123").unwrap());
+    config.set_psk(&[0x5A; 32]);
+    assert_eq!(config.get_password(), b"password123");
+
+    // Keep the storage alive past the drop so the wiped bytes can be inspected
+    let mut slot = MaybeUninit::new(config);
+    unsafe { slot.assume_init_drop() };
+    // SAFETY: `ZeroizeOnDrop` is `repr(transparent)` and `WifiConfig` is `Pod`,
+    // so the bytes left behind are a valid `WifiConfig`.
+    let wiped = unsafe { slot.as_ptr().cast::<WifiConfig>().read() };
+
+    assert_eq!(
+        bytemuck::bytes_of(&wiped),
+        bytemuck::bytes_of(&WifiConfig::default())
+    );
+    assert_eq!(wiped.get_ssid(), b"");
+    assert!(wiped.get_psk().is_none());
+}
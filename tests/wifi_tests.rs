@@ -1,4 +1,4 @@
-use renik::{Error, WifiConfig};
+use renik::{Error, WifiConfig, WifiJoinError, WifiSecurity, derive_hotspot_ssid};
 
 #[test]
 fn test_wifi_config_creation() {
@@ -172,7 +172,7 @@ fn test_wifi_config_default() {
 #[test]
 fn test_wifi_config_memory_layout() {
     // Ensure the structure has the expected size for embedded use
-    let expected_size = 4 + 32 + 1 + 64 + 1 + 2; // magic + ssid + ssid_len + password + password_len + padding
+    let expected_size = 4 + 32 + 1 + 64 + 1 + 1 + 1 + 32 + 1 + 1 + 1 + 6 + 1 + 1 + 1; // magic + ssid + ssid_len + password + password_len + preferred_band + psk_valid + psk + last_error + locked + security + bssid + bssid_valid + hidden + padding
     assert_eq!(core::mem::size_of::<WifiConfig>(), expected_size);
 
     // Ensure proper alignment
@@ -233,3 +233,652 @@ fn test_wifi_config_boundary_conditions() {
         Err(Error::CredentialLengthExceeded)
     ));
 }
+
+#[test]
+fn test_wifi_config_from_wifi_qr() {
+    let config =
+        WifiConfig::from_wifi_qr("WIFI:T:WPA;S:MyNetwork;P:password123;H:false;;").unwrap();
+    assert!(config.is_valid());
+    assert_eq!(config.get_ssid(), b"MyNetwork");
+    assert_eq!(config.get_password(), b"password123");
+
+    // Field order is irrelevant and the trailing terminator is optional
+    let config = WifiConfig::from_wifi_qr("WIFI:P:secret;S:Office").unwrap();
+    assert_eq!(config.get_ssid(), b"Office");
+    assert_eq!(config.get_password(), b"secret");
+
+    // Open network without a password
+    let config = WifiConfig::from_wifi_qr("WIFI:T:nopass;S:Guest;;").unwrap();
+    assert_eq!(config.get_ssid(), b"Guest");
+    assert_eq!(config.get_password(), b"");
+}
+
+#[test]
+fn test_wifi_config_from_wifi_qr_escapes() {
+    let config = WifiConfig::from_wifi_qr(r#"WIFI:S:My\;Net\:work;P:pa\\ss\,\"w;;"#).unwrap();
+    assert_eq!(config.get_ssid(), b"My;Net:work");
+    assert_eq!(config.get_password(), br#"pa\ss,"w"#);
+}
+
+#[test]
+fn test_wifi_config_from_wifi_qr_malformed() {
+    let malformed = [
+        "S:MyNetwork;P:password;;",      // Missing prefix
+        "WIFI:T:WPA;P:password;;",       // Missing SSID
+        "WIFI:S:MyNetwork;Ppassword;;",  // Field without key separator
+        "WIFI:S:MyNetwork;P:password\\", // Dangling escape
+        "WIFI:T:WPA9;S:MyNetwork;;",     // Unknown security type
+        "WIFI:S:MyNetwork;H:maybe;;",    // Invalid hidden flag
+    ];
+
+    for payload in malformed {
+        assert!(
+            matches!(
+                WifiConfig::from_wifi_qr(payload),
+                Err(Error::InvalidCredentialFormat)
+            ),
+            "payload should be rejected: {payload}"
+        );
+    }
+
+    let long_ssid = format!("WIFI:S:{};;", "X".repeat(33));
+    assert!(matches!(
+        WifiConfig::from_wifi_qr(&long_ssid),
+        Err(Error::CredentialLengthExceeded)
+    ));
+}
+
+#[test]
+fn test_wifi_config_set_ssid_truncating() {
+    let mut config = WifiConfig::new(b"Original", b"password123").unwrap();
+
+    assert!(!config.set_ssid_truncating(b"NewNetwork").unwrap());
+    assert_eq!(config.get_ssid(), b"NewNetwork");
+    assert_eq!(config.get_password(), b"password123"); // Password untouched
+
+    let long_ssid = [b'S'; 40];
+    assert!(config.set_ssid_truncating(&long_ssid).unwrap());
+    assert_eq!(config.get_ssid(), &long_ssid[..32]);
+    assert_eq!(config.get_password(), b"password123");
+
+    // Shorter SSID clears the previous tail
+    assert!(!config.set_ssid_truncating(b"A").unwrap());
+    assert_eq!(config.get_ssid(), b"A");
+}
+
+#[test]
+fn test_wifi_config_sanitize_ssid() {
+    let mut config = WifiConfig::new(b"Net\x00work\x1B\x7F\xC3\xA9", b"password").unwrap();
+
+    // Non-mutating copy
+    let mut out = [0u8; 32];
+    let len = config.sanitized_ssid(&mut out).unwrap();
+    assert_eq!(&out[..len], b"Net?work??\xC3\xA9");
+    assert_eq!(config.get_ssid(), b"Net\x00work\x1B\x7F\xC3\xA9"); // Raw SSID untouched
+
+    let mut small = [0u8; 4];
+    assert!(matches!(
+        config.sanitized_ssid(&mut small),
+        Err(Error::BufferTooSmall)
+    ));
+
+    // In-place sanitization preserves the length
+    config.sanitize_ssid().unwrap();
+    assert_eq!(config.get_ssid(), b"Net?work??\xC3\xA9");
+    assert_eq!(config.get_password(), b"password");
+}
+
+#[test]
+fn test_wifi_config_preferred_band() {
+    let mut config = WifiConfig::new(b"DualBand", b"password").unwrap();
+    assert_eq!(config.get_preferred_band(), WifiConfig::BAND_ANY);
+    assert!(!config.prefers_band(WifiConfig::BAND_2_4GHZ));
+    assert!(!config.prefers_band(WifiConfig::BAND_5GHZ));
+    assert!(!config.prefers_band(WifiConfig::BAND_ANY));
+
+    config.set_preferred_band(WifiConfig::BAND_5GHZ);
+    assert_eq!(config.get_preferred_band(), WifiConfig::BAND_5GHZ);
+    assert!(config.prefers_band(WifiConfig::BAND_5GHZ));
+    assert!(!config.prefers_band(WifiConfig::BAND_2_4GHZ));
+    assert!(!config.prefers_band(WifiConfig::BAND_6GHZ));
+
+    // Changing credentials keeps the preference
+    config.set_credentials(b"Other", b"password").unwrap();
+    assert!(config.prefers_band(WifiConfig::BAND_5GHZ));
+}
+
+#[test]
+fn test_wifi_config_is_configured() {
+    let mut config = WifiConfig::default();
+    assert!(config.is_valid());
+    assert!(!config.is_configured());
+
+    config.set_credentials(b"MyNetwork", b"").unwrap();
+    assert!(config.is_configured());
+
+    config.set_credentials(b"", b"password").unwrap();
+    assert!(!config.is_configured());
+}
+
+#[test]
+fn test_wifi_config_psk_cache() {
+    let mut config = WifiConfig::new(b"MyNetwork", b"password123").unwrap();
+    assert_eq!(config.get_psk(), None);
+
+    let psk = [0x5A; 32];
+    config.set_psk(&psk);
+    assert_eq!(config.get_psk(), Some(&psk));
+
+    // The cache survives a byte round-trip
+    let bytes = bytemuck::bytes_of(&config).to_vec();
+    let restored: WifiConfig = bytemuck::pod_read_unaligned(&bytes);
+    assert_eq!(restored.get_psk(), Some(&psk));
+
+    // Changing credentials invalidates the cached key
+    config
+        .set_credentials(b"MyNetwork", b"new_password")
+        .unwrap();
+    assert_eq!(config.get_psk(), None);
+
+    config.set_psk(&psk);
+    config.set_ssid_truncating(b"OtherNetwork").unwrap();
+    assert_eq!(config.get_psk(), None);
+
+    config.set_psk(&psk);
+    config.invalidate_psk();
+    assert_eq!(config.get_psk(), None);
+    assert!(!bytemuck::bytes_of(&config).windows(32).any(|w| w == psk));
+}
+
+#[cfg(feature = "pbkdf2")]
+#[test]
+fn test_wifi_config_derive_psk() {
+    // IEEE 802.11i-2004, Annex H.4.1 test vector
+    let mut config = WifiConfig::new(b"IEEE", b"password").unwrap();
+    config.derive_psk();
+
+    let expected = [
+        0xf4, 0x2c, 0x6f, 0xc5, 0x2d, 0xf0, 0xeb, 0xef, 0x9e, 0xbb, 0x4b, 0x90, 0xb3, 0x8a, 0x5f,
+        0x90, 0x2e, 0x83, 0xfe, 0x1b, 0x13, 0x5a, 0x70, 0xe2, 0x3a, 0xed, 0x76, 0x2e, 0x97, 0x10,
+        0xa1, 0x2e,
+    ];
+    assert_eq!(config.get_psk(), Some(&expected));
+}
+
+#[test]
+fn test_wifi_config_new_zeroed_with_magic() {
+    let config = WifiConfig::new_zeroed_with_magic();
+    assert!(config.is_valid());
+    assert!(!config.is_configured());
+    assert!(bytemuck::bytes_of(&config)[4..].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn test_wifi_config_obfuscated() {
+    let config = WifiConfig::new(b"MyNetwork", b"password123").unwrap();
+    let mut out = [0u8; 32];
+
+    let len = config.obfuscated_ssid(&mut out).unwrap();
+    assert_eq!(&out[..len], b"M*******k");
+
+    let len = config.obfuscated_password(&mut out).unwrap();
+    assert_eq!(&out[..len], b"********");
+
+    // Short SSIDs are masked entirely
+    for (ssid, expected) in [
+        (&b"AB"[..], &b"**"[..]),
+        (b"A", b"*"),
+        (b"", b""),
+        (b"ABC", b"A*C"),
+    ] {
+        let config = WifiConfig::new(ssid, b"").unwrap();
+        let len = config.obfuscated_ssid(&mut out).unwrap();
+        assert_eq!(&out[..len], expected);
+        assert_eq!(config.obfuscated_password(&mut out).unwrap(), 0);
+    }
+
+    // Non-printable edge bytes are masked as well
+    let config = WifiConfig::new(b"\xC3\xA9cole\x1B", b"pw").unwrap();
+    let len = config.obfuscated_ssid(&mut out).unwrap();
+    assert_eq!(&out[..len], b"*******");
+
+    let mut small = [0u8; 4];
+    assert!(matches!(
+        WifiConfig::new(b"MyNetwork", b"pw")
+            .unwrap()
+            .obfuscated_ssid(&mut small),
+        Err(Error::BufferTooSmall)
+    ));
+    assert!(matches!(
+        config.obfuscated_password(&mut small),
+        Err(Error::BufferTooSmall)
+    ));
+}
+
+#[test]
+fn test_wifi_config_from_bytes_relaxed() {
+    let mut config = WifiConfig::new(b"MyNetwork", b"password123").unwrap();
+    config.set_preferred_band(WifiConfig::BAND_5GHZ);
+    let size = core::mem::size_of::<WifiConfig>();
+
+    // Trailing bytes from a longer legacy record are ignored
+    let mut image = bytemuck::bytes_of(&config).to_vec();
+    image.extend_from_slice(&[0xAA; 12]);
+    let loaded = WifiConfig::from_bytes_relaxed(&image).unwrap();
+    assert_eq!(loaded.get_ssid(), b"MyNetwork");
+    assert_eq!(loaded.get_password(), b"password123");
+    assert_eq!(loaded.get_preferred_band(), WifiConfig::BAND_5GHZ);
+
+    // Unaligned input is accepted
+    let mut shifted = vec![0u8];
+    shifted.extend_from_slice(&image);
+    assert!(WifiConfig::from_bytes_relaxed(&shifted[1..]).is_ok());
+
+    assert!(matches!(
+        WifiConfig::from_bytes_relaxed(&image[..size - 1]),
+        Err(Error::CorruptData)
+    ));
+
+    let mut bad_magic = image.clone();
+    bad_magic[0] ^= 0xFF;
+    assert!(matches!(
+        WifiConfig::from_bytes_relaxed(&bad_magic),
+        Err(Error::CorruptData)
+    ));
+
+    // ssid_len directly follows the 96 bytes of SSID and password buffers
+    let mut bad_len = image.clone();
+    bad_len[4 + 32 + 64] = 33;
+    assert!(matches!(
+        WifiConfig::from_bytes_relaxed(&bad_len),
+        Err(Error::CorruptData)
+    ));
+}
+
+#[test]
+fn test_wifi_config_last_error() {
+    let mut config = WifiConfig::new(b"MyNetwork", b"wrong_password").unwrap();
+    assert_eq!(config.get_last_error(), WifiJoinError::None);
+
+    config.set_last_error(WifiJoinError::WrongPassword);
+    assert_eq!(config.get_last_error(), WifiJoinError::WrongPassword);
+
+    // The reason survives a byte round-trip, e.g. across a reboot
+    let bytes = bytemuck::bytes_of(&config).to_vec();
+    let restored: WifiConfig = bytemuck::pod_read_unaligned(&bytes);
+    assert_eq!(restored.get_last_error(), WifiJoinError::WrongPassword);
+
+    for error in [
+        WifiJoinError::ApNotFound,
+        WifiJoinError::AuthTimeout,
+        WifiJoinError::DhcpFailed,
+        WifiJoinError::AssocRejected,
+    ] {
+        config.set_last_error(error);
+        assert_eq!(config.get_last_error(), error);
+    }
+
+    config.clear_last_error();
+    assert_eq!(config.get_last_error(), WifiJoinError::None);
+}
+
+#[test]
+fn test_derive_hotspot_ssid() {
+    let mut out = [0; 32];
+    let len = derive_hotspot_ssid(&[0x00, 0x12, 0x3a, 0xbc, 0x0f], b"RENIK-", &mut out).unwrap();
+    assert_eq!(&out[..len], b"RENIK-3ABC0F");
+    assert!(WifiConfig::new(&out[..len], b"password").is_ok());
+
+    // Short identifiers are zero-padded at the front
+    let len = derive_hotspot_ssid(&[0x42], b"AP", &mut out).unwrap();
+    assert_eq!(&out[..len], b"AP000042");
+
+    // Prefix plus suffix must fit into an SSID
+    let mut big = [0; 64];
+    assert_eq!(
+        derive_hotspot_ssid(&[1, 2, 3], &[b'P'; 26], &mut big).unwrap(),
+        32
+    );
+    assert!(matches!(
+        derive_hotspot_ssid(&[1, 2, 3], &[b'P'; 27], &mut big),
+        Err(Error::BufferTooSmall)
+    ));
+
+    let mut small = [0; 11];
+    assert!(matches!(
+        derive_hotspot_ssid(&[1, 2, 3], b"RENIK-", &mut small),
+        Err(Error::BufferTooSmall)
+    ));
+}
+
+#[test]
+fn test_wifi_config_clone_without_psk() {
+    let mut config = WifiConfig::new(b"MyNetwork", b"password123").unwrap();
+    config.set_preferred_band(WifiConfig::BAND_5GHZ);
+    config.set_last_error(WifiJoinError::DhcpFailed);
+    config.set_psk(&[0x5A; 32]);
+
+    let backup = config.clone_without_psk();
+    assert!(backup.get_psk().is_none());
+    assert!(
+        bytemuck::bytes_of(&backup)[104..136]
+            .iter()
+            .all(|&b| b == 0)
+    );
+    assert_eq!(backup.get_ssid(), b"MyNetwork");
+    assert_eq!(backup.get_password(), b"password123");
+    assert_eq!(backup.get_preferred_band(), WifiConfig::BAND_5GHZ);
+    assert_eq!(backup.get_last_error(), WifiJoinError::DhcpFailed);
+
+    // The original keeps its cached key
+    assert_eq!(config.get_psk(), Some(&[0x5A; 32]));
+}
+
+#[test]
+fn test_wifi_config_arbitrary_bytes_never_panic() {
+    // xorshift32: deterministic pseudo-random input without extra dependencies
+    let mut state = 0x2545_F491_u32;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state
+    };
+
+    let mut bytes = [0; core::mem::size_of::<WifiConfig>()];
+    let mut out = [0; 64];
+    for round in 0..512 {
+        bytes.iter_mut().for_each(|b| *b = next() as u8);
+        if round % 2 == 0 {
+            bytes[..4].copy_from_slice(&0x5749_4649_u32.to_ne_bytes());
+        }
+
+        let mut config: WifiConfig = bytemuck::pod_read_unaligned(&bytes);
+        assert!(config.get_ssid().len() <= 32);
+        assert!(config.get_password().len() <= 64);
+        let _ = config.sanitized_ssid(&mut out);
+        let _ = config.obfuscated_ssid(&mut out);
+        let _ = config.obfuscated_password(&mut out);
+        let _ = config.get_psk();
+        let _ = config.get_last_error();
+        let _ = config.clone_without_psk();
+        let _ = config.sanitize_ssid();
+
+        if let Ok(config) = WifiConfig::from_bytes_relaxed(&bytes) {
+            assert!(config.is_valid());
+        }
+    }
+}
+
+#[test]
+fn test_wifi_config_trim_ssid_trailing_spaces() {
+    let mut config = WifiConfig::new(b" Office WiFi  ", b"password123").unwrap();
+    config.set_psk(&[0x5A; 32]);
+
+    assert!(config.trim_ssid_trailing_spaces().unwrap());
+    assert_eq!(config.get_ssid(), b" Office WiFi"); // Leading space is kept
+    assert!(config.get_psk().is_none());
+    assert!(
+        bytemuck::bytes_of(&config)[4 + 12..4 + 32]
+            .iter()
+            .all(|&b| b == 0)
+    );
+
+    config.set_psk(&[0x5A; 32]);
+    assert!(!config.trim_ssid_trailing_spaces().unwrap());
+    assert!(config.get_psk().is_some());
+
+    let mut spaces = WifiConfig::new(b"   ", b"").unwrap();
+    assert!(spaces.trim_ssid_trailing_spaces().unwrap());
+    assert!(spaces.get_ssid().is_empty());
+}
+
+#[test]
+fn test_wifi_config_ref_from_bytes() {
+    let mut config = WifiConfig::new(b"MyNetwork", b"password123").unwrap();
+    config.set_preferred_band(WifiConfig::BAND_5GHZ);
+    let bytes = bytemuck::bytes_of(&config);
+
+    let view = WifiConfig::ref_from_bytes(bytes).unwrap();
+    assert_eq!(view.get_ssid(), b"MyNetwork");
+    assert_eq!(view.get_password(), b"password123");
+    assert_eq!(view.get_preferred_band(), WifiConfig::BAND_5GHZ);
+    // The view borrows the backing bytes instead of copying them
+    assert!(core::ptr::eq(
+        core::ptr::from_ref::<WifiConfig>(&view).cast::<u8>(),
+        bytes.as_ptr()
+    ));
+
+    // Wrong length
+    assert!(matches!(
+        WifiConfig::ref_from_bytes(&bytes[..bytes.len() - 1]),
+        Err(Error::CorruptData)
+    ));
+
+    // Bad magic and out-of-range lengths
+    let mut corrupt = config;
+    bytemuck::bytes_of_mut(&mut corrupt)[0] ^= 0xFF;
+    assert!(matches!(
+        WifiConfig::ref_from_bytes(bytemuck::bytes_of(&corrupt)),
+        Err(Error::CorruptData)
+    ));
+    let mut corrupt = config;
+    bytemuck::bytes_of_mut(&mut corrupt)[100] = 33; // ssid_len
+    assert!(matches!(
+        WifiConfig::ref_from_bytes(bytemuck::bytes_of(&corrupt)),
+        Err(Error::CorruptData)
+    ));
+}
+
+#[test]
+fn test_wifi_config_remaining_capacity() {
+    let config = WifiConfig::new(b"MyNetwork", b"password123").unwrap();
+    assert_eq!(config.ssid_remaining(), 32 - 9);
+    assert_eq!(config.password_remaining(), 64 - 11);
+
+    let empty = WifiConfig::default();
+    assert_eq!(empty.ssid_remaining(), 32);
+    assert_eq!(empty.password_remaining(), 64);
+
+    let full = WifiConfig::new(&[b'S'; 32], &[b'P'; 64]).unwrap();
+    assert_eq!(full.ssid_remaining(), 0);
+    assert_eq!(full.password_remaining(), 0);
+}
+
+#[test]
+fn test_wifi_config_lengths_are_consistent() {
+    let config = WifiConfig::new(b"MyNetwork", b"password123").unwrap();
+    assert!(config.lengths_are_consistent());
+    assert!(WifiConfig::default().lengths_are_consistent());
+
+    // ssid_len too small: SSID bytes remain past the recorded length
+    let mut bytes = *bytemuck::bytes_of(&config).first_chunk::<148>().unwrap();
+    bytes[100] = 4;
+    let patched: WifiConfig = bytemuck::pod_read_unaligned(&bytes);
+    assert!(!patched.lengths_are_consistent());
+
+    // password_len beyond the buffer
+    let mut bytes = *bytemuck::bytes_of(&config).first_chunk::<148>().unwrap();
+    bytes[101] = 65;
+    let patched: WifiConfig = bytemuck::pod_read_unaligned(&bytes);
+    assert!(!patched.lengths_are_consistent());
+}
+
+#[test]
+fn test_wifi_config_lock() {
+    let mut config = WifiConfig::new(b"MyNetwork ", b"password123").unwrap();
+    assert!(!config.is_locked());
+
+    config.lock();
+    assert!(config.is_locked());
+    assert!(matches!(
+        config.set_credentials(b"Other", b"secret"),
+        Err(Error::ConfigLocked)
+    ));
+    assert!(matches!(
+        config.set_ssid_truncating(b"Other"),
+        Err(Error::ConfigLocked)
+    ));
+    assert!(matches!(config.sanitize_ssid(), Err(Error::ConfigLocked)));
+    assert!(matches!(
+        config.trim_ssid_trailing_spaces(),
+        Err(Error::ConfigLocked)
+    ));
+    assert_eq!(config.get_ssid(), b"MyNetwork ");
+    assert_eq!(config.get_password(), b"password123");
+
+    // The lock flag survives a round trip through bytes
+    let restored: WifiConfig = bytemuck::pod_read_unaligned(bytemuck::bytes_of(&config));
+    assert!(restored.is_locked());
+}
+
+#[test]
+fn test_wifi_config_from_bytes() {
+    let mut config = WifiConfig::new(b"MyNetwork", b"password123").unwrap();
+    config.set_preferred_band(WifiConfig::BAND_2_4GHZ);
+    let image = bytemuck::bytes_of(&config).to_vec();
+
+    let loaded = WifiConfig::from_bytes(&image).unwrap();
+    assert_eq!(loaded.get_ssid(), b"MyNetwork");
+    assert_eq!(loaded.get_password(), b"password123");
+    assert_eq!(loaded.get_preferred_band(), WifiConfig::BAND_2_4GHZ);
+
+    // The length must match exactly
+    let mut longer = image.clone();
+    longer.push(0);
+    assert!(matches!(
+        WifiConfig::from_bytes(&longer),
+        Err(Error::CorruptData)
+    ));
+    assert!(matches!(
+        WifiConfig::from_bytes(&image[..image.len() - 1]),
+        Err(Error::CorruptData)
+    ));
+
+    let mut bad_magic = image.clone();
+    bad_magic[0] ^= 0xFF;
+    assert!(matches!(
+        WifiConfig::from_bytes(&bad_magic),
+        Err(Error::CorruptData)
+    ));
+
+    // password_len follows ssid_len
+    let mut bad_len = image;
+    bad_len[4 + 32 + 64 + 1] = 65;
+    assert!(matches!(
+        WifiConfig::from_bytes(&bad_len),
+        Err(Error::CorruptData)
+    ));
+}
+
+#[test]
+fn test_wifi_config_debug_redacts_password() {
+    let mut config = WifiConfig::new(b"MyNetwork", b"password123").unwrap();
+    config.set_psk(&[0x5A; 32]);
+
+    let output = format!("{config:?}");
+    assert!(output.contains("\"MyNetwork\""));
+    assert!(output.contains("<redacted 11 bytes>"));
+    assert!(!output.contains("password123"));
+    assert!(!output.contains("112, 97, 115, 115")); // "pass" as bytes
+    assert!(!output.contains("90, 90")); // PSK bytes
+
+    let view = WifiConfig::ref_from_bytes(bytemuck::bytes_of(&config)).unwrap();
+    assert!(!format!("{view:?}").contains("password123"));
+}
+
+#[test]
+fn test_wifi_config_security() {
+    let mut config = WifiConfig::new(b"MyNetwork", b"password123").unwrap();
+    assert_eq!(config.get_security(), WifiSecurity::Wpa2Psk);
+
+    for security in [
+        WifiSecurity::Open,
+        WifiSecurity::Wep,
+        WifiSecurity::Wpa2Psk,
+        WifiSecurity::Wpa3Sae,
+        WifiSecurity::WpaWpa2Mixed,
+    ] {
+        config.set_security(security).unwrap();
+        let restored = WifiConfig::from_bytes(bytemuck::bytes_of(&config)).unwrap();
+        assert_eq!(restored.get_security(), security);
+    }
+
+    // The QR code security type is stored
+    let qr = |payload| WifiConfig::from_wifi_qr(payload).unwrap().get_security();
+    assert_eq!(qr("WIFI:T:nopass;S:Guest;;"), WifiSecurity::Open);
+    assert_eq!(qr("WIFI:T:WEP;S:Legacy;P:12345;;"), WifiSecurity::Wep);
+    assert_eq!(qr("WIFI:T:WPA;S:Office;P:secret;;"), WifiSecurity::Wpa2Psk);
+    assert_eq!(qr("WIFI:T:SAE;S:Office;P:secret;;"), WifiSecurity::Wpa3Sae);
+    assert_eq!(qr("WIFI:S:Office;P:secret;;"), WifiSecurity::Wpa2Psk);
+
+    // A locked configuration cannot be downgraded
+    config.set_security(WifiSecurity::Wpa2Psk).unwrap();
+    config.lock();
+    assert!(matches!(
+        config.set_security(WifiSecurity::Open),
+        Err(Error::ConfigLocked)
+    ));
+    assert_eq!(config.get_security(), WifiSecurity::Wpa2Psk);
+}
+
+#[test]
+fn test_wifi_config_bssid() {
+    let mut config = WifiConfig::new(b"MyNetwork", b"password123").unwrap();
+    assert_eq!(config.get_bssid(), None);
+
+    let bssid = [0x02, 0x11, 0x22, 0x33, 0x44, 0x55];
+    config.set_bssid(&bssid).unwrap();
+    assert_eq!(config.get_bssid(), Some(bssid));
+
+    let restored = WifiConfig::from_bytes(bytemuck::bytes_of(&config)).unwrap();
+    assert_eq!(restored.get_bssid(), Some(bssid));
+
+    // An all-zero BSSID is still a pin once set
+    config.set_bssid(&[0; 6]).unwrap();
+    assert_eq!(config.get_bssid(), Some([0; 6]));
+
+    config.set_bssid(&bssid).unwrap();
+    config.clear_bssid().unwrap();
+    assert_eq!(config.get_bssid(), None);
+    assert_eq!(
+        bytemuck::bytes_of(&config),
+        bytemuck::bytes_of(&WifiConfig::new(b"MyNetwork", b"password123").unwrap())
+    );
+
+    // A locked configuration cannot be re-pinned or unpinned
+    config.set_bssid(&bssid).unwrap();
+    config.lock();
+    assert!(matches!(
+        config.set_bssid(&[0x02, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE]),
+        Err(Error::ConfigLocked)
+    ));
+    assert!(matches!(config.clear_bssid(), Err(Error::ConfigLocked)));
+    assert_eq!(config.get_bssid(), Some(bssid));
+}
+
+#[test]
+fn test_wifi_config_hidden() {
+    let mut config = WifiConfig::new(b"HiddenNet", b"password123").unwrap();
+    assert!(!config.is_hidden());
+
+    config.set_hidden(true).unwrap();
+    assert!(config.is_hidden());
+    let restored: WifiConfig = bytemuck::pod_read_unaligned(bytemuck::bytes_of(&config));
+    assert!(restored.is_hidden());
+
+    config.set_hidden(false).unwrap();
+    assert!(!config.is_hidden());
+    let restored: WifiConfig = bytemuck::pod_read_unaligned(bytemuck::bytes_of(&config));
+    assert!(!restored.is_hidden());
+
+    // The QR code hidden flag is stored
+    let qr = WifiConfig::from_wifi_qr("WIFI:S:HiddenNet;P:secret;H:true;;").unwrap();
+    assert!(qr.is_hidden());
+    let qr = WifiConfig::from_wifi_qr("WIFI:S:Office;P:secret;H:false;;").unwrap();
+    assert!(!qr.is_hidden());
+    // A locked configuration keeps its hidden flag
+    config.lock();
+    assert!(matches!(config.set_hidden(true), Err(Error::ConfigLocked)));
+    assert!(!config.is_hidden());
+}